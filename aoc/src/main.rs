@@ -0,0 +1,170 @@
+use auxiliary::cli_opts::{Format, Options};
+use auxiliary::client::{self, Client, Config};
+use auxiliary::io_::{self, Input};
+use auxiliary::runner::{self, Day};
+use auxiliary::solution::Part;
+use auxiliary::submit::{self, History};
+use std::env::args;
+use std::path::Path;
+use std::process::exit;
+
+const SOLUTIONS: [Day; 16] = [
+	Day::of::<day01::Day01>(),
+	Day::of::<day02::Day02>(),
+	Day::of::<day03::Day03>(),
+	Day::of::<day04::Day04>(),
+	Day::of::<day05::Day05>(),
+	Day::of::<day06::Day06>(),
+	Day::of::<day07::Day07>(),
+	Day::of::<day08::Day08>(),
+	Day::of::<day09::Day09>(),
+	Day::of::<day10::Day10>(),
+	Day::of::<day11::Day11>(),
+	Day::of::<day12::Day12>(),
+	Day::of::<day13::Day13>(),
+	Day::of::<day14::Day14>(),
+	Day::of::<day15::Day15>(),
+	Day::of::<day18::Day18>(),
+];
+
+const USAGE: &str = "Usage: aoc run <day|all> [input...] [--input-string <text>] \
+	[--part 1|2] [--format text|json] [--time] [--quiet] [--check] [--watch] [--lossy] \
+	[--bench <runs> [--warmup <runs>] [--report <file.json|file.csv>]]\n       \
+	aoc fetch <day>\n       \
+	aoc submit <day> <1|2> [input]";
+
+fn fail(message: &str) -> ! {
+	eprintln!("{}", message);
+	exit(1);
+}
+
+fn default_input(day: u8) -> Input {
+	match io_::cached_input(day) {
+		Some(cached) => Input::File(cached),
+		None => Input::File(format!("day{:02}/input.txt", day).into()),
+	}
+}
+
+fn run_all(options: &Options) {
+	if !options.inputs.is_empty() || options.watch {
+		fail(USAGE);
+	}
+
+	let mut failed = false;
+	let mut reports = Vec::new();
+	for day in SOLUTIONS {
+		let day_options = Options {
+			inputs: vec![default_input(day.number)],
+			..options.clone()
+		};
+		let result = match options.bench {
+			Some(_) => runner::bench_inputs(day, &day_options)
+				.map(|day_reports| reports.extend(day_reports)),
+			None => {
+				if options.format == Format::Text && !options.quiet {
+					println!("Day {:02}", day.number);
+				}
+				runner::run_inputs(day, &day_options)
+			}
+		};
+		if let Err(error) = result {
+			eprintln!("{}", error);
+			failed = true;
+		}
+	}
+	if let Err(error) = runner::write_report(options, &reports) {
+		eprintln!("{}", error);
+		failed = true;
+	}
+	if failed {
+		exit(1);
+	}
+}
+
+fn run_day(day: &str, mut options: Options) {
+	let day = day.parse::<u8>().unwrap_or_else(|_| fail(USAGE));
+	let day = SOLUTIONS
+		.into_iter()
+		.find(|solved| solved.number == day)
+		.unwrap_or_else(|| fail(&format!("Day {} is not solved.", day)));
+
+	if options.inputs.is_empty() {
+		options.inputs.push(default_input(day.number));
+	}
+	if let Err(error) = runner::run_inputs(day, &options) {
+		fail(&error.to_string());
+	}
+}
+
+fn fetch(day: &str) {
+	let day = day
+		.parse::<u8>()
+		.ok()
+		.filter(|day| (1..=25).contains(day))
+		.unwrap_or_else(|| fail(USAGE));
+	let client = Config::load()
+		.and_then(|config| Client::from_config(&config))
+		.unwrap_or_else(|error| fail(&error.to_string()));
+
+	match client::fetch_input(&client, Path::new(io_::INPUTS_DIR), day) {
+		Ok((path, true)) => println!("Fetched day {:02} input to {}", day, path.display()),
+		Ok((path, false)) => println!("Day {:02} input is already cached at {}", day, path.display()),
+		Err(error) => fail(&error.to_string()),
+	}
+}
+
+fn submit_answer(day: &str, part: &str, input: Option<&String>) {
+	let day = day.parse::<u8>().unwrap_or_else(|_| fail(USAGE));
+	let day = SOLUTIONS
+		.into_iter()
+		.find(|solved| solved.number == day)
+		.unwrap_or_else(|| fail(&format!("Day {} is not solved.", day)));
+	let part = part
+		.parse::<u8>()
+		.ok()
+		.and_then(Part::from_number)
+		.unwrap_or_else(|| fail(USAGE));
+	let input = match input {
+		Some(input) => Input::from_arg(input),
+		None => default_input(day.number),
+	};
+
+	let answer = input
+		.read_to_string()
+		.and_then(|text| (day.solve)(&text, Some(part)))
+		.unwrap_or_else(|error| fail(&error.to_string()))
+		.remove(0)
+		.answer;
+	let client = Config::load()
+		.and_then(|config| Client::from_config(&config))
+		.unwrap_or_else(|error| fail(&error.to_string()));
+	let mut history = History::load(Path::new(submit::HISTORY_FILE))
+		.unwrap_or_else(|error| fail(&error.to_string()));
+
+	println!("Submitting {} for day {:02} part {}", answer, day.number, part.number());
+	match submit::submit(&client, &mut history, day.number, part, &answer) {
+		Ok(response) if response.outcome.is_wrong() => fail(&response.to_string()),
+		Ok(response) => println!("{}", response),
+		Err(error) => fail(&error.to_string()),
+	}
+}
+
+fn main() {
+	let args: Vec<String> = args().skip(1).collect();
+
+	match args.as_slice() {
+		[command, day, rest @ ..] if command == "run" => {
+			let options =
+				Options::parse(rest.to_vec()).unwrap_or_else(|error| fail(&error.to_string()));
+			match day.as_str() {
+				"all" => run_all(&options),
+				day => run_day(day, options),
+			}
+		}
+		[command, day] if command == "fetch" => fetch(day),
+		[command, day, part, input @ ..] if command == "submit" && input.len() <= 1 => {
+			submit_answer(day, part, input.first())
+		}
+		_ => fail(USAGE),
+	}
+}
@@ -0,0 +1,161 @@
+use crate::grid::Grid;
+use crate::output::json_string;
+use crate::Result;
+use std::fmt;
+
+pub const LIT: char = '#';
+pub const DARK: char = '.';
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Answer {
+	Integer(i128),
+	Text(String),
+	Image(Grid<bool>),
+}
+
+impl Answer {
+	// Expected answers are stored as plain text, so the variant has to be
+	// recovered from its shape: digits, a block of lit/dark pixels, or text.
+	pub fn parse(text: &str) -> Answer {
+		if let Ok(integer) = text.trim().parse::<i128>() {
+			return Answer::Integer(integer);
+		}
+		let rows = text.trim_end_matches('\n').lines().collect::<Vec<&str>>();
+		if rows.len() > 1 {
+			let image = Grid::parse(&rows, |pixel| match pixel {
+				LIT => Some(true),
+				DARK => Some(false),
+				_ => None,
+			});
+			if let Ok(image) = image {
+				return Answer::Image(image);
+			}
+		}
+		Answer::Text(text.to_string())
+	}
+
+	pub fn kind(&self) -> &'static str {
+		match self {
+			Answer::Integer(_) => "integer",
+			Answer::Text(_) => "text",
+			Answer::Image(_) => "image",
+		}
+	}
+
+	pub fn to_json(&self) -> String {
+		match self {
+			Answer::Integer(integer) => integer.to_string(),
+			answer => json_string(&answer.to_string()),
+		}
+	}
+}
+
+impl fmt::Display for Answer {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Answer::Integer(integer) => write!(f, "{}", integer),
+			Answer::Text(text) => write!(f, "{}", text),
+			Answer::Image(image) => write!(
+				f,
+				"{}",
+				image.map(|&lit| match lit {
+					true => LIT,
+					false => DARK,
+				})
+			),
+		}
+	}
+}
+
+macro_rules! integer_answers {
+	($($integer:ty),*) => {
+		$(impl From<$integer> for Answer {
+			fn from(integer: $integer) -> Answer {
+				Answer::Integer(integer as i128)
+			}
+		})*
+	};
+}
+
+integer_answers!(i8, i16, i32, i64, u8, u16, u32, u64, usize, isize);
+
+impl From<String> for Answer {
+	fn from(text: String) -> Answer {
+		Answer::Text(text)
+	}
+}
+
+impl From<&str> for Answer {
+	fn from(text: &str) -> Answer {
+		Answer::Text(text.to_string())
+	}
+}
+
+impl From<Grid<bool>> for Answer {
+	fn from(image: Grid<bool>) -> Answer {
+		Answer::Image(image)
+	}
+}
+
+// Parts return a Result when some valid inputs have no answer at all.
+pub trait IntoAnswer {
+	fn into_answer(self) -> Result<Answer>;
+}
+
+impl<T: Into<Answer>> IntoAnswer for T {
+	fn into_answer(self) -> Result<Answer> {
+		Ok(self.into())
+	}
+}
+
+impl<T: Into<Answer>> IntoAnswer for Result<T> {
+	fn into_answer(self) -> Result<Answer> {
+		self.map(Into::into)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn image() -> Answer {
+		Answer::Image(Grid::from_cells(2, vec![true, false, false, true]))
+	}
+
+	#[test]
+	fn test_display() {
+		assert_eq!(Answer::from(24000u64).to_string(), "24000");
+		assert_eq!(Answer::from("CMZ").to_string(), "CMZ");
+		assert_eq!(image().to_string(), "#.\n.#");
+	}
+
+	#[test]
+	fn test_parse_round_trips() {
+		for answer in [Answer::from(-7), Answer::from("MCD"), image()] {
+			assert_eq!(Answer::parse(&answer.to_string()), answer);
+		}
+		assert_eq!(Answer::parse("#.\n.#\n"), image());
+		assert_eq!(Answer::parse("#."), Answer::from("#."));
+	}
+
+	#[test]
+	fn test_to_json() {
+		assert_eq!(Answer::from(13140).to_json(), "13140");
+		assert_eq!(Answer::from("CMZ").to_json(), "\"CMZ\"");
+		assert_eq!(image().to_json(), "\"#.\\n.#\"");
+		assert_eq!(image().kind(), "image");
+	}
+
+	#[test]
+	fn test_into_answer() {
+		assert_eq!(31.into_answer().unwrap(), Answer::from(31));
+		assert_eq!(Ok::<_, crate::Error>("CMZ").into_answer().unwrap(), Answer::from("CMZ"));
+		assert_eq!(
+			Err::<i32, _>(crate::Error::NoAnswer("no path".to_string()))
+				.into_answer()
+				.unwrap_err()
+				.to_string(),
+			"No answer: no path."
+		);
+	}
+}
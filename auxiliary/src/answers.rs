@@ -0,0 +1,144 @@
+use crate::io_::Input;
+use crate::solution::Part;
+use crate::toml_;
+use crate::{Answer, Error, Result};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use toml::{Spanned, Value};
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Answers {
+	pub part1: Option<Answer>,
+	pub part2: Option<Answer>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Verdict {
+	Pass,
+	Fail { expected: Answer },
+	Unknown,
+}
+
+type Table = BTreeMap<String, Spanned<Value>>;
+
+fn answer_value(path: &Path, text: &str, table: &Table, key: &str) -> Result<Option<Answer>> {
+	let Some(value) = table.get(key) else {
+		return Ok(None);
+	};
+	match value.get_ref() {
+		Value::String(answer) => Ok(Some(Answer::parse(answer))),
+		Value::Integer(answer) => Ok(Some(Answer::from(*answer))),
+		_ => {
+			let message = format!("{} must be a string or an integer", key);
+			Err(toml_::invalid(path, text, value, message))
+		}
+	}
+}
+
+impl Answers {
+	pub fn path_for(input: &Path) -> PathBuf {
+		input.with_extension("answers.toml")
+	}
+
+	pub fn parse(path: &Path, text: &str) -> Result<Answers> {
+		let table = toml_::parse::<Table>(path, text)?;
+		Ok(Answers {
+			part1: answer_value(path, text, &table, "part1")?,
+			part2: answer_value(path, text, &table, "part2")?,
+		})
+	}
+
+	pub fn load(path: &Path) -> Result<Answers> {
+		let text = fs::read_to_string(path).map_err(|source| Error::io(path, source))?;
+		Answers::parse(path, &text)
+	}
+
+	pub fn for_input(input: &Input) -> Result<Answers> {
+		match input {
+			Input::File(path) => Answers::load(&Answers::path_for(path)),
+			_ => Err(Error::MissingArgument(
+				"input file to locate the answers for --check",
+			)),
+		}
+	}
+
+	pub fn expected(&self, part: Part) -> Option<&Answer> {
+		match part {
+			Part::One => self.part1.as_ref(),
+			Part::Two => self.part2.as_ref(),
+		}
+	}
+
+	pub fn verify(&self, part: Part, answer: &Answer) -> Verdict {
+		match self.expected(part) {
+			None => Verdict::Unknown,
+			Some(expected) if expected == answer => Verdict::Pass,
+			Some(expected) => Verdict::Fail {
+				expected: expected.clone(),
+			},
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	const PATH: &str = "day01/input.answers.toml";
+
+	fn parse(text: &str) -> Result<Answers> {
+		Answers::parse(Path::new(PATH), text)
+	}
+
+	#[test]
+	fn test_path_for() {
+		assert_eq!(
+			Answers::path_for(Path::new("day07/input.txt")),
+			PathBuf::from("day07/input.answers.toml")
+		);
+		assert_eq!(
+			Answers::path_for(Path::new("inputs/example")),
+			PathBuf::from("inputs/example.answers.toml")
+		);
+	}
+
+	#[test]
+	fn test_parse_and_verify() {
+		let answers = parse("part1 = 24000\npart2 = \"\"\"\n#.\n.#\n\"\"\"\n").unwrap();
+		assert_eq!(answers.verify(Part::One, &Answer::from(24000)), Verdict::Pass);
+		assert_eq!(
+			answers.verify(Part::One, &Answer::from(24001)),
+			Verdict::Fail {
+				expected: Answer::from(24000)
+			}
+		);
+		assert_eq!(
+			answers.verify(Part::Two, &Answer::parse("#.\n.#")),
+			Verdict::Pass
+		);
+		assert!(matches!(
+			answers.verify(Part::Two, &Answer::parse("#.\n##")),
+			Verdict::Fail { .. }
+		));
+	}
+
+	#[test]
+	fn test_missing_part_is_unknown() {
+		let answers = parse("part1 = \"CMZ\"").unwrap();
+		assert_eq!(answers.verify(Part::One, &Answer::from("CMZ")), Verdict::Pass);
+		assert_eq!(answers.verify(Part::Two, &Answer::from("MCD")), Verdict::Unknown);
+	}
+
+	#[test]
+	fn test_parse_error_has_line() {
+		assert!(matches!(
+			parse("part1 = 1\npart2 = \n"),
+			Err(Error::Parse { line: Some(2), .. })
+		));
+		assert_eq!(
+			parse("part1 = 1\n\npart2 = [2]\n").unwrap_err().to_string(),
+			"Parse error in day01/input.answers.toml on line 3: part2 must be a string or an integer"
+		);
+	}
+}
@@ -0,0 +1,229 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
+use std::ops::{Index, IndexMut};
+
+// A handle into an `Arena<T>`; the type parameter keeps handles of different
+// arenas from being mixed up without costing more than the index itself.
+pub struct Id<T> {
+	idx: usize,
+	marker: PhantomData<fn() -> T>,
+}
+
+impl<T> Id<T> {
+	fn new(idx: usize) -> Self {
+		Id {
+			idx,
+			marker: PhantomData,
+		}
+	}
+
+	pub fn index(self) -> usize {
+		self.idx
+	}
+}
+
+// Implemented by hand since deriving would require the same traits of `T`.
+impl<T> Clone for Id<T> {
+	fn clone(&self) -> Self {
+		*self
+	}
+}
+
+impl<T> Copy for Id<T> {}
+
+impl<T> PartialEq for Id<T> {
+	fn eq(&self, other: &Self) -> bool {
+		self.idx == other.idx
+	}
+}
+
+impl<T> Eq for Id<T> {}
+
+impl<T> Hash for Id<T> {
+	fn hash<H: Hasher>(&self, state: &mut H) {
+		self.idx.hash(state);
+	}
+}
+
+impl<T> fmt::Debug for Id<T> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "Id({})", self.idx)
+	}
+}
+
+struct Node<T> {
+	value: T,
+	parent: Option<Id<T>>,
+	children: Vec<Id<T>>,
+	names: HashMap<String, Id<T>>,
+}
+
+pub struct Arena<T> {
+	nodes: Vec<Node<T>>,
+}
+
+impl<T> Arena<T> {
+	pub fn new() -> Self {
+		Arena { nodes: Vec::new() }
+	}
+
+	fn push(&mut self, value: T, parent: Option<Id<T>>) -> Id<T> {
+		let id = Id::new(self.nodes.len());
+		self.nodes.push(Node {
+			value,
+			parent,
+			children: Vec::new(),
+			names: HashMap::new(),
+		});
+		if let Some(parent) = parent {
+			self.nodes[parent.idx].children.push(id);
+		}
+		id
+	}
+
+	pub fn insert(&mut self, value: T) -> Id<T> {
+		self.push(value, None)
+	}
+
+	pub fn add_child(&mut self, parent: Id<T>, value: T) -> Id<T> {
+		self.push(value, Some(parent))
+	}
+
+	// A name that is already taken keeps its child, so seeing the same entry
+	// twice does not grow the tree.
+	pub fn add_named_child(&mut self, parent: Id<T>, name: &str, value: T) -> Id<T> {
+		if let Some(existing) = self.child(parent, name) {
+			return existing;
+		}
+		let id = self.push(value, Some(parent));
+		self.nodes[parent.idx].names.insert(name.to_string(), id);
+		id
+	}
+
+	pub fn child(&self, parent: Id<T>, name: &str) -> Option<Id<T>> {
+		self.nodes[parent.idx].names.get(name).copied()
+	}
+
+	pub fn parent(&self, id: Id<T>) -> Option<Id<T>> {
+		self.nodes[id.idx].parent
+	}
+
+	pub fn children(&self, id: Id<T>) -> &[Id<T>] {
+		&self.nodes[id.idx].children
+	}
+
+	pub fn len(&self) -> usize {
+		self.nodes.len()
+	}
+
+	pub fn is_empty(&self) -> bool {
+		self.nodes.is_empty()
+	}
+
+	pub fn ids(&self) -> impl Iterator<Item = Id<T>> {
+		(0..self.nodes.len()).map(Id::new)
+	}
+
+	pub fn pre_order(&self, root: Id<T>) -> impl Iterator<Item = Id<T>> + '_ {
+		let mut stack = vec![root];
+		std::iter::from_fn(move || {
+			let id = stack.pop()?;
+			stack.extend(self.children(id).iter().rev());
+			Some(id)
+		})
+	}
+
+	// Visiting children last-first and reversing the result yields every
+	// subtree before its root.
+	pub fn post_order(&self, root: Id<T>) -> impl Iterator<Item = Id<T>> {
+		let mut order = Vec::new();
+		let mut stack = vec![root];
+		while let Some(id) = stack.pop() {
+			order.push(id);
+			stack.extend(self.children(id));
+		}
+		order.into_iter().rev()
+	}
+
+	// Folds every subtree below `root`, children first, and returns the result
+	// for each node of it.
+	pub fn fold<A>(&self, root: Id<T>, mut f: impl FnMut(&T, &[&A]) -> A) -> HashMap<Id<T>, A> {
+		let mut results: HashMap<Id<T>, A> = HashMap::new();
+		for id in self.post_order(root) {
+			let result = {
+				let children = self
+					.children(id)
+					.iter()
+					.map(|child| &results[child])
+					.collect::<Vec<&A>>();
+				f(&self[id], &children)
+			};
+			results.insert(id, result);
+		}
+		results
+	}
+}
+
+impl<T> Default for Arena<T> {
+	fn default() -> Self {
+		Arena::new()
+	}
+}
+
+impl<T> Index<Id<T>> for Arena<T> {
+	type Output = T;
+
+	fn index(&self, id: Id<T>) -> &T {
+		&self.nodes[id.idx].value
+	}
+}
+
+impl<T> IndexMut<Id<T>> for Arena<T> {
+	fn index_mut(&mut self, id: Id<T>) -> &mut T {
+		&mut self.nodes[id.idx].value
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn tree() -> (Arena<u32>, Id<u32>) {
+		let mut arena = Arena::new();
+		let root = arena.insert(1);
+		let a = arena.add_named_child(root, "a", 2);
+		arena.add_named_child(a, "c", 4);
+		arena.add_named_child(root, "b", 3);
+		(arena, root)
+	}
+
+	#[test]
+	fn test_links() {
+		let (mut arena, root) = tree();
+		let a = arena.child(root, "a").unwrap();
+		let c = arena.child(a, "c").unwrap();
+		assert_eq!(arena.parent(c), Some(a));
+		assert_eq!(arena.parent(root), None);
+		assert_eq!(arena.child(root, "c"), None);
+		assert_eq!(arena.add_named_child(root, "a", 9), a);
+		assert_eq!(arena.len(), 4);
+		arena[c] += 10;
+		assert_eq!(arena[c], 14);
+	}
+
+	#[test]
+	fn test_traversal_and_fold() {
+		let (arena, root) = tree();
+		let values = |order: Vec<Id<u32>>| order.into_iter().map(|id| arena[id]).collect::<Vec<_>>();
+		assert_eq!(values(arena.pre_order(root).collect()), vec![1, 2, 4, 3]);
+		assert_eq!(values(arena.post_order(root).collect()), vec![4, 2, 3, 1]);
+
+		let sums = arena.fold(root, |value, children| {
+			value + children.iter().copied().sum::<u32>()
+		});
+		assert_eq!(sums[&root], 10);
+		assert_eq!(sums[&arena.child(root, "a").unwrap()], 6);
+	}
+}
@@ -0,0 +1,306 @@
+use crate::cli_opts::Options;
+use crate::io_::Input;
+use crate::output::json_string;
+use crate::solution::{Part, Solution};
+use crate::{Error, Result};
+use std::fmt;
+use std::fs;
+use std::hint::black_box;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+pub const DEFAULT_WARMUP: usize = 3;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Phase {
+	Parse,
+	PartOne,
+	PartTwo,
+}
+
+impl Phase {
+	pub fn name(self) -> &'static str {
+		match self {
+			Phase::Parse => "parse",
+			Phase::PartOne => "part1",
+			Phase::PartTwo => "part2",
+		}
+	}
+}
+
+impl fmt::Display for Phase {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Phase::Parse => write!(f, "parse"),
+			Phase::PartOne => write!(f, "part one"),
+			Phase::PartTwo => write!(f, "part two"),
+		}
+	}
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Stats {
+	pub min: Duration,
+	pub median: Duration,
+	pub max: Duration,
+}
+
+impl Stats {
+	pub fn from_samples(mut samples: Vec<Duration>) -> Stats {
+		assert!(!samples.is_empty(), "cannot summarise zero samples");
+		samples.sort();
+		let middle = samples.len() / 2;
+		let median = match samples.len() % 2 {
+			0 => (samples[middle - 1] + samples[middle]) / 2,
+			_ => samples[middle],
+		};
+		Stats {
+			min: samples[0],
+			median,
+			max: samples[samples.len() - 1],
+		}
+	}
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BenchReport {
+	pub day: u8,
+	pub input: String,
+	pub runs: usize,
+	pub warmup: usize,
+	pub phases: Vec<(Phase, Stats)>,
+}
+
+fn measure<T>(runs: usize, warmup: usize, mut phase: impl FnMut() -> T) -> Stats {
+	for _ in 0..warmup {
+		black_box(phase());
+	}
+	let samples = (0..runs)
+		.map(|_| {
+			let start = Instant::now();
+			black_box(phase());
+			start.elapsed()
+		})
+		.collect();
+	Stats::from_samples(samples)
+}
+
+pub fn bench<S: Solution>(input: &Input, options: &Options) -> Result<BenchReport> {
+	let text = input.read_with(options.encoding)?;
+	let runs = options.bench.unwrap_or(1);
+	let warmup = options.warmup.unwrap_or(DEFAULT_WARMUP);
+	let model = S::parse(&text).map_err(|error| error.with_input(&input.to_string()))?;
+
+	let mut phases = vec![(Phase::Parse, measure(runs, warmup, || S::parse(&text)))];
+	if options.part != Some(Part::Two) {
+		phases.push((
+			Phase::PartOne,
+			measure(runs, warmup, || S::part_one(&model)),
+		));
+	}
+	if options.part != Some(Part::One) {
+		phases.push((
+			Phase::PartTwo,
+			measure(runs, warmup, || S::part_two(&model)),
+		));
+	}
+
+	Ok(BenchReport {
+		day: S::DAY,
+		input: input.to_string(),
+		runs,
+		warmup,
+		phases,
+	})
+}
+
+impl BenchReport {
+	pub fn to_json(&self) -> String {
+		let phases = self
+			.phases
+			.iter()
+			.map(|(phase, stats)| {
+				format!(
+					"{{\"phase\":\"{}\",\"min_ns\":{},\"median_ns\":{},\"max_ns\":{}}}",
+					phase.name(),
+					stats.min.as_nanos(),
+					stats.median.as_nanos(),
+					stats.max.as_nanos()
+				)
+			})
+			.collect::<Vec<_>>();
+		format!(
+			"{{\"day\":{},\"input\":{},\"runs\":{},\"warmup\":{},\"phases\":[{}]}}",
+			self.day,
+			json_string(&self.input),
+			self.runs,
+			self.warmup,
+			phases.join(",")
+		)
+	}
+
+	pub fn csv_rows(&self) -> impl Iterator<Item = String> + '_ {
+		self.phases.iter().map(move |(phase, stats)| {
+			format!(
+				"{},{},{},{},{},{},{}",
+				self.day,
+				csv_field(&self.input),
+				phase.name(),
+				self.runs,
+				stats.min.as_nanos(),
+				stats.median.as_nanos(),
+				stats.max.as_nanos()
+			)
+		})
+	}
+}
+
+impl fmt::Display for BenchReport {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(
+			f,
+			"Day {:02} ({}), {} runs after {} warm-up runs:",
+			self.day, self.input, self.runs, self.warmup
+		)?;
+		for (phase, stats) in &self.phases {
+			write!(
+				f,
+				"\n  {:<8}  min {:>12}  median {:>12}  max {:>12}",
+				phase.to_string(),
+				format!("{:?}", stats.min),
+				format!("{:?}", stats.median),
+				format!("{:?}", stats.max)
+			)?;
+		}
+		Ok(())
+	}
+}
+
+fn csv_field(text: &str) -> String {
+	match text.contains([',', '"', '\n']) {
+		true => format!("\"{}\"", text.replace('"', "\"\"")),
+		false => text.to_string(),
+	}
+}
+
+pub const CSV_HEADER: &str = "day,input,phase,runs,min_ns,median_ns,max_ns";
+
+pub fn to_csv(reports: &[BenchReport]) -> String {
+	let mut csv = String::from(CSV_HEADER);
+	for row in reports.iter().flat_map(BenchReport::csv_rows) {
+		csv.push('\n');
+		csv.push_str(&row);
+	}
+	csv.push('\n');
+	csv
+}
+
+pub fn to_json(reports: &[BenchReport]) -> String {
+	let reports = reports.iter().map(BenchReport::to_json).collect::<Vec<_>>();
+	format!("[{}]\n", reports.join(",\n"))
+}
+
+// The format follows the extension so reports can be diffed or loaded into a
+// spreadsheet when comparing runs between commits.
+pub fn write_report(path: &Path, reports: &[BenchReport]) -> Result<()> {
+	let contents = match path.extension().is_some_and(|extension| extension == "csv") {
+		true => to_csv(reports),
+		false => to_json(reports),
+	};
+	fs::write(path, contents).map_err(|source| Error::io(path, source))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	struct Sum;
+
+	impl Solution for Sum {
+		const DAY: u8 = 1;
+
+		type Model = Vec<i32>;
+		type PartOne = i32;
+		type PartTwo = usize;
+
+		fn parse(input: &str) -> Result<Vec<i32>> {
+			input
+				.lines()
+				.enumerate()
+				.map(|(idx, line)| line.parse().map_err(|_| Error::parse(idx + 1, line)))
+				.collect()
+		}
+
+		fn part_one(model: &Vec<i32>) -> i32 {
+			model.iter().sum()
+		}
+
+		fn part_two(model: &Vec<i32>) -> usize {
+			model.len()
+		}
+	}
+
+	fn millis(values: &[u64]) -> Vec<Duration> {
+		values.iter().copied().map(Duration::from_millis).collect()
+	}
+
+	fn report() -> BenchReport {
+		let stats = Stats::from_samples(millis(&[1, 2, 3]));
+		BenchReport {
+			day: 7,
+			input: "day07/input.txt".to_string(),
+			runs: 3,
+			warmup: 1,
+			phases: vec![(Phase::Parse, stats), (Phase::PartTwo, stats)],
+		}
+	}
+
+	#[test]
+	fn test_stats() {
+		let stats = Stats::from_samples(millis(&[5, 1, 3]));
+		assert_eq!(stats.min, Duration::from_millis(1));
+		assert_eq!(stats.median, Duration::from_millis(3));
+		assert_eq!(stats.max, Duration::from_millis(5));
+		assert_eq!(
+			Stats::from_samples(millis(&[4, 1, 2, 8])).median,
+			Duration::from_millis(3)
+		);
+	}
+
+	#[test]
+	fn test_bench_selected_phases() {
+		let options = Options {
+			part: Some(Part::One),
+			bench: Some(4),
+			warmup: Some(0),
+			..Options::default()
+		};
+		let report = bench::<Sum>(&Input::Text("1\n2\n3".to_string()), &options).unwrap();
+		assert_eq!((report.day, report.runs, report.warmup), (1, 4, 0));
+		assert_eq!(
+			report
+				.phases
+				.iter()
+				.map(|(phase, _)| *phase)
+				.collect::<Vec<_>>(),
+			vec![Phase::Parse, Phase::PartOne]
+		);
+		assert!(bench::<Sum>(&Input::Text("x".to_string()), &options).is_err());
+	}
+
+	#[test]
+	fn test_report_formats() {
+		assert_eq!(
+			to_csv(&[report()]),
+			"day,input,phase,runs,min_ns,median_ns,max_ns\n\
+			7,day07/input.txt,parse,3,1000000,2000000,3000000\n\
+			7,day07/input.txt,part2,3,1000000,2000000,3000000\n"
+		);
+		assert_eq!(
+			report().to_json(),
+			"{\"day\":7,\"input\":\"day07/input.txt\",\"runs\":3,\"warmup\":1,\"phases\":[\
+			{\"phase\":\"parse\",\"min_ns\":1000000,\"median_ns\":2000000,\"max_ns\":3000000},\
+			{\"phase\":\"part2\",\"min_ns\":1000000,\"median_ns\":2000000,\"max_ns\":3000000}]}"
+		);
+	}
+}
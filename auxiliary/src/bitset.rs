@@ -0,0 +1,199 @@
+use std::ops::{BitAnd, BitOr, Not};
+
+pub trait Word: Copy + Eq + BitAnd<Output = Self> + BitOr<Output = Self> + Not<Output = Self> {
+	const ZERO: Self;
+	const BITS: u32;
+
+	fn bit(idx: usize) -> Self;
+	fn count_ones(self) -> u32;
+	fn trailing_zeros(self) -> u32;
+}
+
+macro_rules! words {
+	($($word:ty),*) => {
+		$(impl Word for $word {
+			const ZERO: Self = 0;
+			const BITS: u32 = <$word>::BITS;
+
+			fn bit(idx: usize) -> Self {
+				1 << idx
+			}
+
+			fn count_ones(self) -> u32 {
+				<$word>::count_ones(self)
+			}
+
+			fn trailing_zeros(self) -> u32 {
+				<$word>::trailing_zeros(self)
+			}
+		})*
+	};
+}
+
+words!(u64, u128);
+
+// A set of small indices packed into a single word, so set operations are a
+// handful of instructions and never allocate.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct BitSet<W> {
+	bits: W,
+}
+
+pub type Letters = BitSet<u64>;
+
+// Maps a-z to 0..26 and A-Z to 26..52, which makes the index one less than the
+// item priority used by the puzzles.
+pub fn letter_index(letter: char) -> Option<usize> {
+	match letter {
+		'a'..='z' => Some(letter as usize - 'a' as usize),
+		'A'..='Z' => Some(letter as usize - 'A' as usize + 26),
+		_ => None,
+	}
+}
+
+impl<W: Word> BitSet<W> {
+	pub const CAPACITY: usize = W::BITS as usize;
+
+	pub fn new() -> Self {
+		BitSet { bits: W::ZERO }
+	}
+
+	fn bit(idx: usize) -> W {
+		assert!(idx < Self::CAPACITY, "index {} out of bitset range", idx);
+		W::bit(idx)
+	}
+
+	pub fn insert(&mut self, idx: usize) -> bool {
+		let inserted = !self.contains(idx);
+		self.bits = self.bits | Self::bit(idx);
+		inserted
+	}
+
+	pub fn remove(&mut self, idx: usize) -> bool {
+		let removed = self.contains(idx);
+		self.bits = self.bits & !Self::bit(idx);
+		removed
+	}
+
+	pub fn contains(&self, idx: usize) -> bool {
+		idx < Self::CAPACITY && self.bits & W::bit(idx) != W::ZERO
+	}
+
+	pub fn len(&self) -> usize {
+		self.bits.count_ones() as usize
+	}
+
+	pub fn is_empty(&self) -> bool {
+		self.bits == W::ZERO
+	}
+
+	pub fn union(self, other: Self) -> Self {
+		BitSet {
+			bits: self.bits | other.bits,
+		}
+	}
+
+	pub fn intersection(self, other: Self) -> Self {
+		BitSet {
+			bits: self.bits & other.bits,
+		}
+	}
+
+	pub fn iter(&self) -> Iter<W> {
+		Iter { bits: self.bits }
+	}
+}
+
+impl<W: Word> Default for BitSet<W> {
+	fn default() -> Self {
+		BitSet::new()
+	}
+}
+
+impl<W: Word> BitOr for BitSet<W> {
+	type Output = Self;
+
+	fn bitor(self, other: Self) -> Self {
+		self.union(other)
+	}
+}
+
+impl<W: Word> BitAnd for BitSet<W> {
+	type Output = Self;
+
+	fn bitand(self, other: Self) -> Self {
+		self.intersection(other)
+	}
+}
+
+impl<W: Word> FromIterator<usize> for BitSet<W> {
+	fn from_iter<I: IntoIterator<Item = usize>>(indices: I) -> Self {
+		let mut set = BitSet::new();
+		for idx in indices {
+			set.insert(idx);
+		}
+		set
+	}
+}
+
+pub struct Iter<W> {
+	bits: W,
+}
+
+impl<W: Word> Iterator for Iter<W> {
+	type Item = usize;
+
+	fn next(&mut self) -> Option<usize> {
+		if self.bits == W::ZERO {
+			return None;
+		}
+		let idx = self.bits.trailing_zeros() as usize;
+		self.bits = self.bits & !W::bit(idx);
+		Some(idx)
+	}
+}
+
+impl<W: Word> IntoIterator for BitSet<W> {
+	type Item = usize;
+	type IntoIter = Iter<W>;
+
+	fn into_iter(self) -> Iter<W> {
+		self.iter()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_set_operations() {
+		let left = [1, 5, 63].into_iter().collect::<BitSet<u64>>();
+		let right = [5, 7, 63].into_iter().collect::<BitSet<u64>>();
+		assert_eq!((left | right).iter().collect::<Vec<_>>(), vec![1, 5, 7, 63]);
+		assert_eq!((left & right).iter().collect::<Vec<_>>(), vec![5, 63]);
+		assert_eq!(left.len(), 3);
+		assert!(left.contains(63) && !left.contains(64));
+
+		let mut set = BitSet::<u128>::new();
+		assert!(set.insert(127));
+		assert!(!set.insert(127));
+		assert!(set.remove(127));
+		assert!(set.is_empty());
+	}
+
+	#[test]
+	fn test_letters() {
+		assert_eq!(letter_index('a'), Some(0));
+		assert_eq!(letter_index('Z'), Some(51));
+		assert_eq!(letter_index('!'), None);
+		let letters = "vJrwpWtwJgWr".chars().filter_map(letter_index).collect::<Letters>();
+		assert_eq!(letters.len(), 8);
+	}
+
+	#[test]
+	#[should_panic]
+	fn test_out_of_range() {
+		BitSet::<u64>::new().insert(64);
+	}
+}
@@ -0,0 +1,226 @@
+use crate::io_::{self, Encoding, Input};
+use crate::solution::Part;
+use crate::{Error, Result};
+use std::env::args;
+use std::path::PathBuf;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Format {
+	#[default]
+	Text,
+	Json,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Options {
+	pub inputs: Vec<Input>,
+	pub part: Option<Part>,
+	pub format: Format,
+	pub time: bool,
+	pub quiet: bool,
+	pub check: bool,
+	pub watch: bool,
+	pub encoding: Encoding,
+	pub bench: Option<usize>,
+	pub warmup: Option<usize>,
+	pub report: Option<PathBuf>,
+}
+
+fn option_value<I>(args: &mut I, option: &'static str) -> Result<String>
+where
+	I: Iterator<Item = String>,
+{
+	args.next().ok_or(Error::MissingArgument(option))
+}
+
+fn count_value<I>(args: &mut I, name: &str, option: &'static str) -> Result<usize>
+where
+	I: Iterator<Item = String>,
+{
+	let raw_count = option_value(args, option)?;
+	raw_count
+		.parse::<usize>()
+		.map_err(|_| Error::invalid_argument(name, &raw_count))
+}
+
+impl Options {
+	pub fn parse<I>(args: I) -> Result<Options>
+	where
+		I: IntoIterator<Item = String>,
+	{
+		let mut options = Options::default();
+		let mut args = args.into_iter();
+
+		while let Some(arg) = args.next() {
+			match arg.as_str() {
+				"--input-string" => options.inputs.push(Input::Text(option_value(
+					&mut args,
+					"input string after --input-string",
+				)?)),
+				"--part" => {
+					let raw_part = option_value(&mut args, "part number after --part")?;
+					options.part = Some(
+						raw_part
+							.parse::<u8>()
+							.ok()
+							.and_then(Part::from_number)
+							.ok_or_else(|| Error::invalid_argument("--part", &raw_part))?,
+					);
+				}
+				"--format" => {
+					let raw_format = option_value(&mut args, "format after --format")?;
+					options.format = match raw_format.as_str() {
+						"text" => Format::Text,
+						"json" => Format::Json,
+						_ => return Err(Error::invalid_argument("--format", &raw_format)),
+					};
+				}
+				"--time" => options.time = true,
+				"--quiet" => options.quiet = true,
+				"--check" => options.check = true,
+				"--watch" => options.watch = true,
+				"--lossy" => options.encoding = Encoding::Lossy,
+				"--bench" => {
+					let runs = count_value(&mut args, "--bench", "run count after --bench")?;
+					if runs == 0 {
+						return Err(Error::invalid_argument("--bench", "0"));
+					}
+					options.bench = Some(runs);
+				}
+				"--warmup" => {
+					options.warmup = Some(count_value(
+						&mut args,
+						"--warmup",
+						"run count after --warmup",
+					)?)
+				}
+				"--report" => {
+					options.report =
+						Some(option_value(&mut args, "report path after --report")?.into())
+				}
+				_ if arg.starts_with("--") => return Err(Error::UnknownOption(arg)),
+				_ => options.inputs.push(Input::from_arg(&arg)),
+			}
+		}
+
+		if options.bench.is_none() && (options.warmup.is_some() || options.report.is_some()) {
+			return Err(Error::MissingArgument("run count with --bench"));
+		}
+		if options.watch && options.bench.is_some() {
+			return Err(Error::ConflictingOptions("--bench", "--watch"));
+		}
+		Ok(options)
+	}
+}
+
+pub fn provided_options(day: u8) -> Result<Options> {
+	let mut options = Options::parse(args().skip(1))?;
+
+	if options.inputs.is_empty() {
+		let cached = io_::cached_input(day).ok_or(Error::MissingArgument("input file path"))?;
+		options.inputs.push(Input::File(cached));
+	}
+	Ok(options)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn to_args(args: &[&str]) -> Vec<String> {
+		args.iter().map(|arg| arg.to_string()).collect()
+	}
+
+	#[test]
+	fn test_parse_inputs() {
+		assert_eq!(
+			Options::parse(to_args(&["a.txt", "-", "--input-string", "1,2,3", "b.txt"]))
+				.unwrap()
+				.inputs,
+			vec![
+				Input::File(PathBuf::from("a.txt")),
+				Input::Stdin,
+				Input::Text("1,2,3".to_string()),
+				Input::File(PathBuf::from("b.txt")),
+			]
+		);
+	}
+
+	#[test]
+	fn test_parse_flags() {
+		let options = Options::parse(to_args(&[
+			"--part",
+			"2",
+			"--format",
+			"json",
+			"--time",
+			"--quiet",
+			"--check",
+			"--watch",
+			"--lossy",
+			"input.txt",
+		]))
+		.unwrap();
+		assert_eq!(options.part, Some(Part::Two));
+		assert_eq!(options.format, Format::Json);
+		assert!(options.time);
+		assert!(options.quiet);
+		assert!(options.check);
+		assert!(options.watch);
+		assert_eq!(options.encoding, Encoding::Lossy);
+		assert_eq!(
+			options.inputs,
+			vec![Input::File(PathBuf::from("input.txt"))]
+		);
+	}
+
+	#[test]
+	fn test_parse_bench() {
+		let options = Options::parse(to_args(&[
+			"--bench",
+			"10",
+			"--warmup",
+			"2",
+			"--report",
+			"bench.csv",
+		]))
+		.unwrap();
+		assert_eq!(options.bench, Some(10));
+		assert_eq!(options.warmup, Some(2));
+		assert_eq!(options.report, Some(PathBuf::from("bench.csv")));
+		assert!(matches!(
+			Options::parse(to_args(&["--bench", "0"])),
+			Err(Error::InvalidArgument { .. })
+		));
+		assert!(matches!(
+			Options::parse(to_args(&["--warmup", "2"])),
+			Err(Error::MissingArgument(_))
+		));
+		match Options::parse(to_args(&["--bench", "3", "--watch"])) {
+			Err(error @ Error::ConflictingOptions("--bench", "--watch")) => {
+				assert_eq!(error.to_string(), "--bench cannot be combined with --watch.")
+			}
+			_ => panic!("expected conflicting options"),
+		}
+	}
+
+	#[test]
+	fn test_parse_rejects_invalid_values() {
+		assert!(matches!(
+			Options::parse(to_args(&["--input-string"])),
+			Err(Error::MissingArgument(_))
+		));
+		assert!(matches!(
+			Options::parse(to_args(&["--part", "3"])),
+			Err(Error::InvalidArgument { .. })
+		));
+		assert!(matches!(
+			Options::parse(to_args(&["--format", "xml"])),
+			Err(Error::InvalidArgument { .. })
+		));
+		assert!(matches!(
+			Options::parse(to_args(&["--verbose"])),
+			Err(Error::UnknownOption(option)) if option == "--verbose"
+		));
+	}
+}
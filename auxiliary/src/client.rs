@@ -0,0 +1,279 @@
+use crate::io_::input_path;
+use crate::solution::Part;
+use crate::submit::Response;
+use crate::toml_;
+use crate::{Answer, Error, Result};
+use std::collections::BTreeMap;
+use std::env;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use toml::{Spanned, Value};
+
+pub const YEAR: u16 = 2022;
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+pub const CONFIG_FILE: &str = ".aoc.toml";
+pub const SESSION_VAR: &str = "AOC_SESSION";
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+
+const USER_AGENT: &str = "github.com/Weyzu/aoc2022";
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Config {
+	pub session: Option<String>,
+	pub base_url: Option<String>,
+}
+
+type Table = BTreeMap<String, Spanned<Value>>;
+
+fn config_value(path: &Path, text: &str, table: &Table, key: &str) -> Result<Option<String>> {
+	let Some(value) = table.get(key) else {
+		return Ok(None);
+	};
+	match value.get_ref() {
+		Value::String(value) => Ok(Some(value.trim().to_string())),
+		_ => Err(toml_::invalid(path, text, value, format!("{} must be a string", key))),
+	}
+}
+
+impl Config {
+	pub fn parse(path: &Path, text: &str) -> Result<Config> {
+		let table = toml_::parse::<Table>(path, text)?;
+		Ok(Config {
+			session: config_value(path, text, &table, "session")?,
+			base_url: config_value(path, text, &table, "base_url")?,
+		})
+	}
+
+	// Environment variables take precedence over the config file so a session
+	// can be swapped without editing it.
+	pub fn load() -> Result<Config> {
+		let mut config = match fs::read_to_string(CONFIG_FILE) {
+			Ok(text) => Config::parse(Path::new(CONFIG_FILE), &text)?,
+			Err(error) if error.kind() == io::ErrorKind::NotFound => Config::default(),
+			Err(error) => return Err(Error::io(CONFIG_FILE, error)),
+		};
+		if let Ok(session) = env::var(SESSION_VAR) {
+			config.session = Some(session);
+		}
+		if let Ok(base_url) = env::var(BASE_URL_VAR) {
+			config.base_url = Some(base_url);
+		}
+		Ok(config)
+	}
+}
+
+pub struct Client {
+	agent: ureq::Agent,
+	base_url: String,
+	session: String,
+}
+
+impl Client {
+	pub fn new(base_url: &str, session: &str) -> Client {
+		Client {
+			agent: ureq::AgentBuilder::new().user_agent(USER_AGENT).build(),
+			base_url: base_url.trim_end_matches('/').to_string(),
+			session: session.to_string(),
+		}
+	}
+
+	pub fn from_config(config: &Config) -> Result<Client> {
+		let session = config.session.as_deref().ok_or(Error::MissingArgument(
+			"AOC_SESSION session cookie or a session entry in .aoc.toml",
+		))?;
+		Ok(Client::new(
+			config.base_url.as_deref().unwrap_or(DEFAULT_BASE_URL),
+			session,
+		))
+	}
+
+	pub fn day_url(&self, day: u8) -> String {
+		format!("{}/{}/day/{}", self.base_url, YEAR, day)
+	}
+
+	pub fn input(&self, day: u8) -> Result<String> {
+		let url = format!("{}/input", self.day_url(day));
+		self.agent
+			.get(&url)
+			.set("Cookie", &format!("session={}", self.session))
+			.call()
+			.map_err(|error| http_error(&url, error))?
+			.into_string()
+			.map_err(|error| Error::http(&url, error.to_string()))
+	}
+
+	pub fn submit(&self, day: u8, part: Part, answer: &Answer) -> Result<Response> {
+		let url = format!("{}/answer", self.day_url(day));
+		let level = part.number().to_string();
+		let answer = answer.to_string();
+		self.agent
+			.post(&url)
+			.set("Cookie", &format!("session={}", self.session))
+			.send_form(&[("level", &level), ("answer", &answer)])
+			.map_err(|error| http_error(&url, error))?
+			.into_string()
+			.map(|response| Response::parse(&response))
+			.map_err(|error| Error::http(&url, error.to_string()))
+	}
+}
+
+fn http_error(url: &str, error: ureq::Error) -> Error {
+	match error {
+		ureq::Error::Status(status, _) => Error::http(url, format!("HTTP status {}", status)),
+		ureq::Error::Transport(transport) => match transport.message() {
+			Some(message) => Error::http(url, format!("{}: {}", transport.kind(), message)),
+			None => Error::http(url, transport.kind().to_string()),
+		},
+	}
+}
+
+// Inputs never change once published, so a cached file is always reused.
+pub fn fetch_input(client: &Client, inputs_dir: &Path, day: u8) -> Result<(PathBuf, bool)> {
+	let path = input_path(inputs_dir, day);
+	if path.is_file() {
+		return Ok((path, false));
+	}
+
+	let input = client.input(day)?;
+	fs::create_dir_all(inputs_dir).map_err(|source| Error::io(inputs_dir, source))?;
+	fs::write(&path, input).map_err(|source| Error::io(&path, source))?;
+	Ok((path, true))
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+	use super::*;
+	use crate::submit::Outcome;
+	use std::io::{BufRead, BufReader, Read, Write};
+	use std::net::TcpListener;
+	use std::sync::mpsc::{self, Receiver};
+	use std::thread;
+
+	pub struct Request {
+		pub request_line: String,
+		pub headers: Vec<String>,
+		pub body: String,
+	}
+
+	// Serves the given responses in order and hands every request back to the test.
+	pub fn stub_server(responses: Vec<(u16, &'static str)>) -> (String, Receiver<Request>) {
+		let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+		let base_url = format!("http://{}", listener.local_addr().unwrap());
+		let (sender, receiver) = mpsc::channel();
+
+		thread::spawn(move || {
+			for (status, response) in responses {
+				let (stream, _) = listener.accept().unwrap();
+				let mut reader = BufReader::new(stream);
+				let mut request_line = String::new();
+				reader.read_line(&mut request_line).unwrap();
+				let mut headers = Vec::new();
+				loop {
+					let mut header = String::new();
+					reader.read_line(&mut header).unwrap();
+					if header.trim().is_empty() {
+						break;
+					}
+					headers.push(header.trim().to_string());
+				}
+				let length = headers
+					.iter()
+					.find_map(|header| header.strip_prefix("Content-Length: "))
+					.map_or(0, |length| length.parse::<usize>().unwrap());
+				let mut body_bytes = vec![0; length];
+				reader.read_exact(&mut body_bytes).unwrap();
+				let mut stream = reader.into_inner();
+				write!(
+					stream,
+					"HTTP/1.1 {} Stub\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+					status,
+					response.len(),
+					response
+				)
+				.unwrap();
+				sender
+					.send(Request {
+						request_line: request_line.trim().to_string(),
+						headers,
+						body: String::from_utf8(body_bytes).unwrap(),
+					})
+					.unwrap();
+			}
+		});
+		(base_url, receiver)
+	}
+
+	pub fn temp_dir(name: &str) -> PathBuf {
+		let dir = env::temp_dir().join(format!("aoc-{}-{}", name, std::process::id()));
+		let _ = fs::remove_dir_all(&dir);
+		dir
+	}
+
+	#[test]
+	fn test_config_parse() {
+		let parse = |text| Config::parse(Path::new(CONFIG_FILE), text);
+		assert_eq!(
+			parse("session = \"abc123\"\nbase_url = \"http://localhost:8080\"").unwrap(),
+			Config {
+				session: Some("abc123".to_string()),
+				base_url: Some("http://localhost:8080".to_string()),
+			}
+		);
+		assert_eq!(parse("").unwrap(), Config::default());
+		assert_eq!(
+			parse("session = \"abc123\"\nbase_url = 1").unwrap_err().to_string(),
+			"Parse error in .aoc.toml on line 2: base_url must be a string"
+		);
+		assert!(matches!(parse("session = "), Err(Error::Parse { line: Some(1), .. })));
+		assert!(Client::from_config(&Config::default()).is_err());
+	}
+
+	#[test]
+	fn test_fetch_input_once() {
+		let (base_url, requests) = stub_server(vec![(200, "1000\n2000\n")]);
+		let client = Client::new(&base_url, "abc123");
+		let inputs_dir = temp_dir("fetch");
+
+		let (path, fetched) = fetch_input(&client, &inputs_dir, 1).unwrap();
+		assert!(fetched);
+		assert_eq!(path, inputs_dir.join("day01.txt"));
+		assert_eq!(fs::read_to_string(&path).unwrap(), "1000\n2000\n");
+		let request = requests.recv().unwrap();
+		assert_eq!(request.request_line, "GET /2022/day/1/input HTTP/1.1");
+		assert!(request.headers.iter().any(|header| header == "Cookie: session=abc123"));
+
+		assert_eq!(fetch_input(&client, &inputs_dir, 1).unwrap(), (path, false));
+		fs::remove_dir_all(&inputs_dir).unwrap();
+	}
+
+	#[test]
+	fn test_submit() {
+		let (base_url, requests) = stub_server(vec![(
+			200,
+			"<article><p>That's not the right answer; your answer is too low.</p></article>",
+		)]);
+		let client = Client::new(&base_url, "abc123");
+
+		assert_eq!(
+			client.submit(5, Part::Two, &Answer::from("MCD")).unwrap().outcome,
+			Outcome::TooLow
+		);
+		let request = requests.recv().unwrap();
+		assert_eq!(request.request_line, "POST /2022/day/5/answer HTTP/1.1");
+		assert_eq!(request.body, "level=2&answer=MCD");
+	}
+
+	#[test]
+	fn test_fetch_input_error() {
+		let (base_url, _requests) = stub_server(vec![(400, "Please log in")]);
+		let client = Client::new(&base_url, "expired");
+		let inputs_dir = temp_dir("fetch-error");
+
+		assert!(matches!(
+			fetch_input(&client, &inputs_dir, 2),
+			Err(Error::Http { .. })
+		));
+		assert!(!input_path(&inputs_dir, 2).exists());
+	}
+}
@@ -0,0 +1,172 @@
+use crate::{Error, Result};
+use std::fmt;
+
+pub const MAX_SHOWN: usize = 10;
+
+// A problem found by a parser, pointing at the offending token. The token has
+// to be a slice of the line handed to `Diagnostics::report`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Fault<'a> {
+	pub token: &'a str,
+	pub message: String,
+}
+
+impl<'a> Fault<'a> {
+	pub fn new(token: &'a str, message: impl Into<String>) -> Self {
+		Fault {
+			token,
+			message: message.into(),
+		}
+	}
+
+	pub fn after(text: &'a str, message: impl Into<String>) -> Self {
+		Fault::new(&text[text.len()..], message)
+	}
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Diagnostic {
+	pub line: usize,
+	pub column: usize,
+	pub width: usize,
+	pub text: String,
+	pub message: String,
+}
+
+impl Diagnostic {
+	pub fn new(line: usize, text: &str, fault: Fault) -> Self {
+		let start = (fault.token.as_ptr() as usize)
+			.checked_sub(text.as_ptr() as usize)
+			.filter(|&start| start + fault.token.len() <= text.len())
+			.unwrap_or(text.len());
+		Diagnostic {
+			line,
+			column: text[..start].chars().count() + 1,
+			width: fault.token.chars().count().max(1),
+			text: text.to_string(),
+			message: fault.message,
+		}
+	}
+
+	fn render(&self, input: &str, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let gutter = " ".repeat(self.line.to_string().len());
+		writeln!(f, "error: {}", self.message)?;
+		writeln!(f, "{}--> {}:{}:{}", gutter, input, self.line, self.column)?;
+		writeln!(f, "{} |", gutter)?;
+		writeln!(f, "{} | {}", self.line, self.text)?;
+		write!(
+			f,
+			"{} | {}{}",
+			gutter,
+			" ".repeat(self.column - 1),
+			"^".repeat(self.width)
+		)
+	}
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Diagnostics {
+	input: Option<String>,
+	diagnostics: Vec<Diagnostic>,
+}
+
+impl Diagnostics {
+	pub fn new() -> Self {
+		Diagnostics::default()
+	}
+
+	pub fn report(&mut self, line: usize, text: &str, fault: Fault) {
+		self.diagnostics.push(Diagnostic::new(line, text, fault));
+	}
+
+	pub fn is_empty(&self) -> bool {
+		self.diagnostics.is_empty()
+	}
+
+	pub fn len(&self) -> usize {
+		self.diagnostics.len()
+	}
+
+	pub fn iter(&self) -> impl Iterator<Item = &Diagnostic> {
+		self.diagnostics.iter()
+	}
+
+	pub fn with_input(mut self, input: &str) -> Self {
+		self.input = Some(input.to_string());
+		self
+	}
+
+	pub fn finish<T>(self, value: T) -> Result<T> {
+		match self.is_empty() {
+			true => Ok(value),
+			false => Err(Error::Diagnostics(self)),
+		}
+	}
+}
+
+impl fmt::Display for Diagnostics {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let input = self.input.as_deref().unwrap_or("<input>");
+		for (idx, diagnostic) in self.diagnostics.iter().take(MAX_SHOWN).enumerate() {
+			if idx > 0 {
+				writeln!(f)?;
+			}
+			diagnostic.render(input, f)?;
+			writeln!(f)?;
+		}
+		if self.len() > MAX_SHOWN {
+			writeln!(f, "... and {} more", self.len() - MAX_SHOWN)?;
+		}
+		match self.len() {
+			1 => write!(f, "Found 1 error in {}.", input),
+			errors => write!(f, "Found {} errors in {}.", errors, input),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_column_from_token() {
+		let text = "move 1 from x to 3";
+		let diagnostic = Diagnostic::new(4, text, Fault::new(&text[12..13], "bad stack"));
+		assert_eq!((diagnostic.line, diagnostic.column, diagnostic.width), (4, 13, 1));
+		let diagnostic = Diagnostic::new(4, text, Fault::after(text, "missing stack"));
+		assert_eq!((diagnostic.column, diagnostic.width), (19, 1));
+		let diagnostic = Diagnostic::new(4, text, Fault::new("elsewhere", "foreign token"));
+		assert_eq!(diagnostic.column, 19);
+	}
+
+	#[test]
+	fn test_display() {
+		let text = "move 1 from x to 3";
+		let mut diagnostics = Diagnostics::new();
+		diagnostics.report(12, text, Fault::new(&text[12..13], "invalid number \"x\""));
+		diagnostics.report(13, "move", Fault::after("move", "expected a crate count"));
+		assert_eq!(
+			diagnostics.with_input("day05/input.txt").to_string(),
+			"error: invalid number \"x\"\n  \
+			--> day05/input.txt:12:13\n   \
+			|\n\
+			12 | move 1 from x to 3\n   \
+			|             ^\n\
+			\n\
+			error: expected a crate count\n  \
+			--> day05/input.txt:13:5\n   \
+			|\n\
+			13 | move\n   \
+			|     ^\n\
+			Found 2 errors in day05/input.txt."
+		);
+	}
+
+	#[test]
+	fn test_finish() {
+		assert_eq!(Diagnostics::new().finish(7).unwrap(), 7);
+		let mut diagnostics = Diagnostics::new();
+		diagnostics.report(1, "x", Fault::new("x", "bad"));
+		assert!(matches!(diagnostics.finish(7), Err(Error::Diagnostics(_))));
+	}
+}
@@ -0,0 +1,135 @@
+use crate::point::{Coordinate, Point2};
+use crate::{Error, Result};
+use std::str::FromStr;
+
+// Directions follow the screen convention of grids and puzzle inputs: north
+// points towards smaller y.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Direction {
+	North,
+	NorthEast,
+	East,
+	SouthEast,
+	South,
+	SouthWest,
+	West,
+	NorthWest,
+}
+
+use Direction::*;
+
+pub const CARDINAL: [Direction; 4] = [North, East, South, West];
+pub const ORDINAL: [Direction; 4] = [NorthEast, SouthEast, SouthWest, NorthWest];
+pub const ALL: [Direction; 8] = [
+	North, NorthEast, East, SouthEast, South, SouthWest, West, NorthWest,
+];
+
+impl Direction {
+	// Accepts both the relative L/R/U/D and the compass N/E/S/W spellings.
+	pub fn from_char(letter: char) -> Option<Direction> {
+		match letter {
+			'U' | 'N' => Some(North),
+			'R' | 'E' => Some(East),
+			'D' | 'S' => Some(South),
+			'L' | 'W' => Some(West),
+			_ => None,
+		}
+	}
+
+	pub fn is_cardinal(self) -> bool {
+		matches!(self, North | East | South | West)
+	}
+
+	// Turns clockwise in steps of 45 degrees; negative steps turn anticlockwise.
+	pub fn rotate(self, eighths: i32) -> Direction {
+		ALL[(self as i32 + eighths).rem_euclid(8) as usize]
+	}
+
+	pub fn turn_right(self) -> Direction {
+		self.rotate(2)
+	}
+
+	pub fn turn_left(self) -> Direction {
+		self.rotate(-2)
+	}
+
+	pub fn opposite(self) -> Direction {
+		self.rotate(4)
+	}
+
+	pub fn offset(self) -> (i8, i8) {
+		match self {
+			North => (0, -1),
+			NorthEast => (1, -1),
+			East => (1, 0),
+			SouthEast => (1, 1),
+			South => (0, 1),
+			SouthWest => (-1, 1),
+			West => (-1, 0),
+			NorthWest => (-1, -1),
+		}
+	}
+
+	pub fn apply<T: Coordinate>(self, point: Point2<T>) -> Option<Point2<T>> {
+		point.offset(self.offset())
+	}
+}
+
+impl FromStr for Direction {
+	type Err = Error;
+
+	// Only the text is known here, so callers that know the line can report
+	// their own located error instead.
+	fn from_str(raw_direction: &str) -> Result<Direction> {
+		let mut letters = raw_direction.chars();
+		match (letters.next().and_then(Direction::from_char), letters.next()) {
+			(Some(direction), None) => Ok(direction),
+			_ => Err(Error::Parse {
+				input: None,
+				line: None,
+				message: format!("invalid direction {:?}", raw_direction),
+			}),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_parse() {
+		assert_eq!("U".parse::<Direction>().unwrap(), North);
+		assert_eq!("W".parse::<Direction>().unwrap(), West);
+		assert_eq!(Direction::from_char('R'), Some(East));
+		assert_eq!(
+			"X".parse::<Direction>().unwrap_err().to_string(),
+			"Parse error: invalid direction \"X\""
+		);
+		assert!("UD".parse::<Direction>().is_err());
+	}
+
+	#[test]
+	fn test_turning() {
+		assert_eq!(North.turn_right(), East);
+		assert_eq!(North.turn_left(), West);
+		assert_eq!(SouthWest.opposite(), NorthEast);
+		assert_eq!(NorthWest.rotate(1), North);
+		assert_eq!(North.rotate(-9), NorthWest);
+		assert!(CARDINAL.iter().all(|direction| direction.is_cardinal()));
+		assert!(!ORDINAL.iter().any(|direction| direction.is_cardinal()));
+	}
+
+	#[test]
+	fn test_apply() {
+		let point = Point2::<usize>::new(0, 5);
+		assert_eq!(South.apply(point), Some(Point2::new(0, 6)));
+		assert_eq!(NorthEast.apply(point), Some(Point2::new(1, 4)));
+		assert_eq!(SouthWest.apply(point), None);
+		let centre = Point2::new(3, 3);
+		for direction in ALL {
+			let moved = direction.apply(centre).unwrap();
+			assert_eq!(direction.opposite().apply(moved), Some(centre));
+		}
+	}
+}
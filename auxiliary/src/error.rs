@@ -0,0 +1,143 @@
+use crate::diagnostics::Diagnostics;
+use std::fmt;
+use std::io;
+use std::path::PathBuf;
+
+#[derive(Debug)]
+pub enum Error {
+	MissingArgument(&'static str),
+	UnknownOption(String),
+	ConflictingOptions(&'static str, &'static str),
+	InvalidArgument { argument: String, value: String },
+	Io { path: PathBuf, source: io::Error },
+	Utf8 { input: String, line: usize },
+	Parse { input: Option<String>, line: Option<usize>, message: String },
+	Diagnostics(Diagnostics),
+	NoAnswer(String),
+	CheckFailed(usize),
+	Http { url: String, message: String },
+	Submission(String),
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+impl Error {
+	pub fn invalid_argument(argument: &str, value: &str) -> Self {
+		Error::InvalidArgument {
+			argument: argument.to_string(),
+			value: value.to_string(),
+		}
+	}
+
+	pub fn io(path: impl Into<PathBuf>, source: io::Error) -> Self {
+		Error::Io {
+			path: path.into(),
+			source,
+		}
+	}
+
+	pub fn parse(line: usize, message: impl Into<String>) -> Self {
+		Error::Parse {
+			input: None,
+			line: Some(line),
+			message: message.into(),
+		}
+	}
+
+	// Parsers only see the text, so the runner names the input afterwards.
+	pub fn with_input(self, input: &str) -> Self {
+		match self {
+			Error::Diagnostics(diagnostics) => Error::Diagnostics(diagnostics.with_input(input)),
+			Error::Parse { line, message, .. } => Error::Parse {
+				input: Some(input.to_string()),
+				line,
+				message,
+			},
+			error => error,
+		}
+	}
+
+	pub fn http(url: &str, message: impl Into<String>) -> Self {
+		Error::Http {
+			url: url.to_string(),
+			message: message.into(),
+		}
+	}
+}
+
+impl fmt::Display for Error {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Error::MissingArgument(argument) => write!(f, "Missing {}.", argument),
+			Error::UnknownOption(option) => write!(f, "Unknown option {}.", option),
+			Error::ConflictingOptions(option, other) => {
+				write!(f, "{} cannot be combined with {}.", option, other)
+			}
+			Error::InvalidArgument { argument, value } => {
+				write!(f, "Invalid value \"{}\" for {}.", value, argument)
+			}
+			Error::Io { path, source } => write!(f, "Cannot read {}: {}", path.display(), source),
+			Error::Utf8 { input, line } => write!(
+				f,
+				"Invalid UTF-8 in {} on line {}; use --lossy to replace it.",
+				input, line
+			),
+			Error::Parse { input, line, message } => {
+				write!(f, "Parse error")?;
+				if let Some(input) = input {
+					write!(f, " in {}", input)?;
+				}
+				if let Some(line) = line {
+					write!(f, " on line {}", line)?;
+				}
+				write!(f, ": {}", message)
+			}
+			Error::Diagnostics(diagnostics) => write!(f, "{}", diagnostics),
+			Error::NoAnswer(reason) => write!(f, "No answer: {}.", reason),
+			Error::CheckFailed(1) => write!(f, "1 answer does not match the expected one."),
+			Error::CheckFailed(failed) => {
+				write!(f, "{} answers do not match the expected ones.", failed)
+			}
+			Error::Http { url, message } => write!(f, "Request to {} failed: {}", url, message),
+			Error::Submission(message) => write!(f, "{}", message),
+		}
+	}
+}
+
+impl std::error::Error for Error {
+	fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+		match self {
+			Error::Io { source, .. } => Some(source),
+			_ => None,
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_missing_argument() {
+		assert_eq!(
+			Error::MissingArgument("part number after --part").to_string(),
+			"Missing part number after --part."
+		);
+	}
+
+	#[test]
+	fn test_with_input_names_parse_errors() {
+		let error = Error::parse(3, "invalid file size");
+		assert_eq!(error.to_string(), "Parse error on line 3: invalid file size");
+		assert_eq!(
+			error.with_input("day07/input.txt").to_string(),
+			"Parse error in day07/input.txt on line 3: invalid file size"
+		);
+		let unplaced = Error::Parse {
+			input: Some(".aoc.toml".to_string()),
+			line: None,
+			message: "unexpected end of input".to_string(),
+		};
+		assert_eq!(unplaced.to_string(), "Parse error in .aoc.toml: unexpected end of input");
+	}
+}
@@ -0,0 +1,276 @@
+use crate::direction::Direction;
+use crate::point::Point2;
+use crate::{Error, Result};
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+/// A rectangular grid of cells addressed by `Point2<usize>`. Points follow the
+/// input's x/y convention, so x selects the column and y the row counted from
+/// the top.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Grid<T> {
+	cells: Vec<T>,
+	width: usize,
+	height: usize,
+}
+
+impl<T> Grid<T> {
+	pub fn new(width: usize, height: usize, fill: T) -> Self
+	where
+		T: Clone,
+	{
+		Grid {
+			cells: vec![fill; width * height],
+			width,
+			height,
+		}
+	}
+
+	pub fn from_cells(width: usize, cells: Vec<T>) -> Self {
+		assert!(
+			width > 0 && cells.len().is_multiple_of(width),
+			"{} cells do not fill rows of width {}",
+			cells.len(),
+			width
+		);
+		Grid {
+			height: cells.len() / width,
+			cells,
+			width,
+		}
+	}
+
+	pub fn parse<I, S, F>(lines: I, mut cell: F) -> Result<Self>
+	where
+		I: IntoIterator<Item = S>,
+		S: AsRef<str>,
+		F: FnMut(char) -> Option<T>,
+	{
+		let mut cells = Vec::new();
+		let mut width = None;
+		let mut height = 0;
+
+		for (idx, line) in lines.into_iter().enumerate() {
+			let line = line.as_ref();
+			let row_start = cells.len();
+			for character in line.chars() {
+				cells.push(cell(character).ok_or_else(|| {
+					Error::parse(idx + 1, format!("unexpected character {:?}", character))
+				})?);
+			}
+			let row_width = cells.len() - row_start;
+			match width {
+				None => width = Some(row_width),
+				Some(width) if width != row_width => {
+					return Err(Error::parse(
+						idx + 1,
+						format!("expected {} cells, found {}", width, row_width),
+					))
+				}
+				_ => {}
+			}
+			height += 1;
+		}
+
+		Ok(Grid {
+			cells,
+			width: width.unwrap_or(0),
+			height,
+		})
+	}
+
+	pub fn width(&self) -> usize {
+		self.width
+	}
+
+	pub fn height(&self) -> usize {
+		self.height
+	}
+
+	pub fn contains(&self, point: Point2<usize>) -> bool {
+		point.x < self.width && point.y < self.height
+	}
+
+	pub fn get(&self, point: Point2<usize>) -> Option<&T> {
+		match self.contains(point) {
+			true => self.cells.get(point.y * self.width + point.x),
+			false => None,
+		}
+	}
+
+	pub fn get_mut(&mut self, point: Point2<usize>) -> Option<&mut T> {
+		match self.contains(point) {
+			true => self.cells.get_mut(point.y * self.width + point.x),
+			false => None,
+		}
+	}
+
+	pub fn towards(&self, point: Point2<usize>, direction: Direction) -> Option<Point2<usize>> {
+		direction.apply(point).filter(|&next| self.contains(next))
+	}
+
+	pub fn row(&self, row: usize) -> &[T] {
+		&self.cells[row * self.width..(row + 1) * self.width]
+	}
+
+	pub fn column(&self, column: usize) -> impl Iterator<Item = &T> {
+		assert!(column < self.width, "column {} out of bounds", column);
+		self.cells.iter().skip(column).step_by(self.width)
+	}
+
+	pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+		self.cells.chunks(self.width.max(1))
+	}
+
+	pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+		(0..self.width).map(move |column| self.column(column))
+	}
+
+	pub fn positions(&self) -> impl Iterator<Item = Point2<usize>> {
+		let width = self.width;
+		(0..self.height).flat_map(move |y| (0..width).map(move |x| Point2::new(x, y)))
+	}
+
+	pub fn iter(&self) -> impl Iterator<Item = (Point2<usize>, &T)> {
+		self.positions().zip(self.cells.iter())
+	}
+
+	pub fn position(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<Point2<usize>> {
+		self.iter()
+			.find(|(_, cell)| predicate(cell))
+			.map(|(position, _)| position)
+	}
+
+	pub fn ray(
+		&self,
+		from: Point2<usize>,
+		direction: Direction,
+	) -> impl Iterator<Item = (Point2<usize>, &T)> {
+		let mut current = Some(from);
+		std::iter::from_fn(move || {
+			current = self.towards(current?, direction);
+			current.map(|position| (position, &self[position]))
+		})
+	}
+
+	pub fn neighbours<'a>(
+		&'a self,
+		point: Point2<usize>,
+		directions: &'a [Direction],
+	) -> impl Iterator<Item = Point2<usize>> + 'a {
+		directions
+			.iter()
+			.filter_map(move |&direction| self.towards(point, direction))
+	}
+
+	pub fn map<U>(&self, mut transform: impl FnMut(&T) -> U) -> Grid<U> {
+		Grid {
+			cells: self.cells.iter().map(&mut transform).collect(),
+			width: self.width,
+			height: self.height,
+		}
+	}
+}
+
+impl<T> Index<Point2<usize>> for Grid<T> {
+	type Output = T;
+
+	fn index(&self, point: Point2<usize>) -> &T {
+		self.get(point)
+			.unwrap_or_else(|| panic!("point {:?} out of bounds", point))
+	}
+}
+
+impl<T> IndexMut<Point2<usize>> for Grid<T> {
+	fn index_mut(&mut self, point: Point2<usize>) -> &mut T {
+		self.get_mut(point)
+			.unwrap_or_else(|| panic!("point {:?} out of bounds", point))
+	}
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		for (idx, row) in self.rows().enumerate() {
+			if idx > 0 {
+				writeln!(f)?;
+			}
+			for cell in row {
+				write!(f, "{}", cell)?;
+			}
+		}
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::direction::{ALL, CARDINAL};
+
+	fn digits() -> Grid<u32> {
+		Grid::parse(["123", "456", "789"], |c| c.to_digit(10)).unwrap()
+	}
+
+	#[test]
+	fn test_parse() {
+		let grid = digits();
+		assert_eq!((grid.width(), grid.height()), (3, 3));
+		assert_eq!(grid[Point2::new(2, 1)], 6);
+		assert_eq!(grid.get(Point2::new(0, 3)), None);
+		assert!(matches!(
+			Grid::parse(["12", "3"], |c| c.to_digit(10)),
+			Err(Error::Parse { line: Some(2), .. })
+		));
+		assert!(matches!(
+			Grid::parse(["12", "3x"], |c| c.to_digit(10)),
+			Err(Error::Parse { line: Some(2), .. })
+		));
+	}
+
+	#[test]
+	fn test_rows_and_columns() {
+		let grid = digits();
+		assert_eq!(grid.row(1), &[4, 5, 6]);
+		assert_eq!(grid.column(2).copied().collect::<Vec<_>>(), vec![3, 6, 9]);
+		assert_eq!(
+			grid.columns()
+				.map(|column| column.sum::<u32>())
+				.collect::<Vec<_>>(),
+			vec![12, 15, 18]
+		);
+	}
+
+	#[test]
+	fn test_rays() {
+		let grid = digits();
+		let center = Point2::new(1, 1);
+		let values = |direction| grid.ray(center, direction).map(|(_, v)| *v).collect::<Vec<_>>();
+		assert_eq!(values(Direction::North), vec![2]);
+		assert_eq!(values(Direction::East), vec![6]);
+		assert_eq!(values(Direction::SouthEast), vec![9]);
+		assert_eq!(grid.ray(Point2::new(0, 0), Direction::East).count(), 2);
+		assert_eq!(grid.ray(Point2::new(0, 0), Direction::North).count(), 0);
+	}
+
+	#[test]
+	fn test_neighbours() {
+		let grid = digits();
+		let center = Point2::new(1, 1);
+		assert_eq!(grid.neighbours(center, &CARDINAL).count(), 4);
+		assert_eq!(grid.neighbours(center, &ALL).count(), 8);
+		assert_eq!(
+			grid.neighbours(Point2::new(0, 0), &CARDINAL).collect::<Vec<_>>(),
+			vec![Point2::new(1, 0), Point2::new(0, 1)]
+		);
+		assert_eq!(grid.neighbours(Point2::new(2, 2), &ALL).count(), 3);
+		assert_eq!(grid.towards(center, Direction::North), Some(Point2::new(1, 0)));
+		assert_eq!(grid.towards(center, Direction::SouthWest), Some(Point2::new(0, 2)));
+		assert_eq!(grid.towards(Point2::new(2, 0), Direction::East), None);
+		assert_eq!(grid.get(Point2::new(2, 0)), Some(&3));
+	}
+
+	#[test]
+	fn test_display() {
+		assert_eq!(digits().to_string(), "123\n456\n789");
+	}
+}
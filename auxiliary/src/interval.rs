@@ -0,0 +1,190 @@
+use crate::point::Coordinate;
+
+// Intervals are half-open, so adjacent ones share a bound without overlapping
+// and `len` is simply `end - start`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Interval<T> {
+	pub start: T,
+	pub end: T,
+}
+
+impl<T: Coordinate> Interval<T> {
+	pub const fn new(start: T, end: T) -> Self {
+		Interval { start, end }
+	}
+
+	pub fn inclusive(first: T, last: T) -> Self {
+		Interval::new(first, last + T::ONE)
+	}
+
+	pub fn is_empty(self) -> bool {
+		self.start >= self.end
+	}
+
+	pub fn len(self) -> T {
+		match self.is_empty() {
+			true => T::ZERO,
+			false => self.end - self.start,
+		}
+	}
+
+	pub fn contains(self, value: T) -> bool {
+		self.start <= value && value < self.end
+	}
+
+	pub fn contains_interval(self, other: Self) -> bool {
+		other.is_empty() || (self.start <= other.start && other.end <= self.end)
+	}
+
+	pub fn overlaps(self, other: Self) -> bool {
+		self.intersection(other).is_some()
+	}
+
+	pub fn intersection(self, other: Self) -> Option<Self> {
+		let intersection = Interval::new(self.start.max(other.start), self.end.min(other.end));
+		(!intersection.is_empty()).then_some(intersection)
+	}
+}
+
+// Kept sorted, with no empty, overlapping or touching intervals, so every
+// operation is a binary search plus a splice.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct IntervalSet<T> {
+	intervals: Vec<Interval<T>>,
+}
+
+impl<T: Coordinate> IntervalSet<T> {
+	pub fn new() -> Self {
+		IntervalSet {
+			intervals: Vec::new(),
+		}
+	}
+
+	pub fn insert(&mut self, interval: Interval<T>) {
+		if interval.is_empty() {
+			return;
+		}
+		let first = self
+			.intervals
+			.partition_point(|existing| existing.end < interval.start);
+		let last = self
+			.intervals
+			.partition_point(|existing| existing.start <= interval.end);
+		let mut merged = interval;
+		if first < last {
+			merged.start = merged.start.min(self.intervals[first].start);
+			merged.end = merged.end.max(self.intervals[last - 1].end);
+		}
+		self.intervals.splice(first..last, [merged]);
+	}
+
+	pub fn subtract(&mut self, interval: Interval<T>) {
+		if interval.is_empty() {
+			return;
+		}
+		let first = self
+			.intervals
+			.partition_point(|existing| existing.end <= interval.start);
+		let last = self
+			.intervals
+			.partition_point(|existing| existing.start < interval.end);
+		if first >= last {
+			return;
+		}
+		let before = Interval::new(self.intervals[first].start, interval.start);
+		let after = Interval::new(interval.end, self.intervals[last - 1].end);
+		self.intervals.splice(
+			first..last,
+			[before, after].into_iter().filter(|rest| !rest.is_empty()),
+		);
+	}
+
+	pub fn contains(&self, value: T) -> bool {
+		let idx = self.intervals.partition_point(|existing| existing.end <= value);
+		self.intervals
+			.get(idx)
+			.is_some_and(|existing| existing.contains(value))
+	}
+
+	pub fn is_empty(&self) -> bool {
+		self.intervals.is_empty()
+	}
+
+	pub fn total_len(&self) -> T {
+		self.intervals
+			.iter()
+			.fold(T::ZERO, |total, interval| total + interval.len())
+	}
+
+	pub fn iter(&self) -> impl Iterator<Item = Interval<T>> + '_ {
+		self.intervals.iter().copied()
+	}
+}
+
+impl<T: Coordinate> FromIterator<Interval<T>> for IntervalSet<T> {
+	fn from_iter<I: IntoIterator<Item = Interval<T>>>(intervals: I) -> Self {
+		let mut set = IntervalSet::new();
+		for interval in intervals {
+			set.insert(interval);
+		}
+		set
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_interval() {
+		let interval = Interval::inclusive(2, 4);
+		assert_eq!(interval, Interval::new(2, 5));
+		assert_eq!(interval.len(), 3);
+		assert!(interval.contains(4) && !interval.contains(5));
+		assert!(interval.contains_interval(Interval::new(3, 5)));
+		assert!(!interval.contains_interval(Interval::new(3, 6)));
+		assert!(interval.overlaps(Interval::new(4, 8)));
+		assert!(!interval.overlaps(Interval::new(5, 8)));
+		assert_eq!(interval.intersection(Interval::new(0, 3)), Some(Interval::new(2, 3)));
+		assert_eq!(Interval::new(3, 1).len(), 0);
+	}
+
+	#[test]
+	fn test_merging() {
+		let set = [
+			Interval::new(10, 12),
+			Interval::new(0, 3),
+			Interval::new(3, 5),
+			Interval::new(11, 15),
+			Interval::new(7, 7),
+		]
+		.into_iter()
+		.collect::<IntervalSet<i32>>();
+		assert_eq!(
+			set.iter().collect::<Vec<_>>(),
+			vec![Interval::new(0, 5), Interval::new(10, 15)]
+		);
+		assert_eq!(set.total_len(), 10);
+		assert!(set.contains(4) && !set.contains(5) && set.contains(10));
+
+		let mut set = set;
+		set.insert(Interval::new(-2, 11));
+		assert_eq!(set.iter().collect::<Vec<_>>(), vec![Interval::new(-2, 15)]);
+	}
+
+	#[test]
+	fn test_subtract() {
+		let mut set = [Interval::new(0, 10), Interval::new(20, 30)]
+			.into_iter()
+			.collect::<IntervalSet<u32>>();
+		set.subtract(Interval::new(3, 5));
+		set.subtract(Interval::new(8, 22));
+		set.subtract(Interval::new(40, 50));
+		assert_eq!(
+			set.iter().collect::<Vec<_>>(),
+			vec![Interval::new(0, 3), Interval::new(5, 8), Interval::new(22, 30)]
+		);
+		set.subtract(Interval::new(0, 30));
+		assert!(set.is_empty());
+	}
+}
@@ -0,0 +1,214 @@
+use crate::{Error, Result};
+use std::fmt;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, Read};
+use std::path::{Path, PathBuf};
+
+pub const INPUTS_DIR: &str = "inputs";
+
+const BOM: char = '\u{feff}';
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Encoding {
+	#[default]
+	Strict,
+	Lossy,
+}
+
+// Strict decoding reports the line of the first invalid byte.
+fn decode(bytes: Vec<u8>, encoding: Encoding, source: &str) -> Result<String> {
+	String::from_utf8(bytes).or_else(|error| match encoding {
+		Encoding::Lossy => Ok(String::from_utf8_lossy(error.as_bytes()).into_owned()),
+		Encoding::Strict => {
+			let valid = &error.as_bytes()[..error.utf8_error().valid_up_to()];
+			Err(Error::Utf8 {
+				input: source.to_string(),
+				line: 1 + valid.iter().filter(|&&byte| byte == b'\n').count(),
+			})
+		}
+	})
+}
+
+// Inputs saved on Windows or by editors that pad lines would otherwise break
+// exact matches such as "dir" or "[]".
+pub fn normalize(text: &str) -> String {
+	text.strip_prefix(BOM)
+		.unwrap_or(text)
+		.split('\n')
+		.map(str::trim_end)
+		.collect::<Vec<&str>>()
+		.join("\n")
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Input {
+	File(PathBuf),
+	Stdin,
+	Text(String),
+}
+
+impl Input {
+	pub fn from_arg(arg: &str) -> Self {
+		match arg {
+			"-" => Input::Stdin,
+			path => Input::File(PathBuf::from(path)),
+		}
+	}
+
+	pub fn reader(&self) -> Result<Box<dyn BufRead + '_>> {
+		match self {
+			Input::File(path) => Ok(open(path)?),
+			Input::Stdin => Ok(Box::new(io::stdin().lock())),
+			Input::Text(text) => Ok(Box::new(text.as_bytes())),
+		}
+	}
+
+	pub fn read_lines(&self) -> Result<Vec<String>> {
+		self.read_lines_with(Encoding::Strict)
+	}
+
+	pub fn read_lines_with(&self, encoding: Encoding) -> Result<Vec<String>> {
+		Ok(self.read_with(encoding)?.lines().map(str::to_string).collect())
+	}
+
+	pub fn read_to_string(&self) -> Result<String> {
+		self.read_with(Encoding::Strict)
+	}
+
+	pub fn read_with(&self, encoding: Encoding) -> Result<String> {
+		let mut bytes = Vec::new();
+		self.reader()?
+			.read_to_end(&mut bytes)
+			.map_err(|source| self.error(source))?;
+		Ok(normalize(&decode(bytes, encoding, &self.to_string())?))
+	}
+
+	fn error(&self, source: io::Error) -> Error {
+		Error::io(self.to_string(), source)
+	}
+}
+
+impl fmt::Display for Input {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Input::File(path) => write!(f, "{}", path.display()),
+			Input::Stdin => write!(f, "<stdin>"),
+			Input::Text(_) => write!(f, "<input-string>"),
+		}
+	}
+}
+
+fn open(path: &Path) -> Result<Box<dyn BufRead>> {
+	match File::open(path) {
+		Ok(file) => Ok(Box::new(BufReader::new(file))),
+		Err(source) => Err(Error::io(path, source)),
+	}
+}
+
+// Groups of non-blank lines, each paired with the 1-based line number it
+// starts on so parsers can report positions within the whole input.
+pub fn split_groups<I, S>(lines: I) -> impl Iterator<Item = (usize, Vec<S>)>
+where
+	I: IntoIterator<Item = S>,
+	S: AsRef<str>,
+{
+	let mut lines = lines.into_iter().zip(1..).peekable();
+	std::iter::from_fn(move || {
+		while lines.next_if(|(line, _)| line.as_ref().is_empty()).is_some() {}
+		let &(_, first_line) = lines.peek()?;
+		let group = std::iter::from_fn(|| lines.next_if(|(line, _)| !line.as_ref().is_empty()))
+			.map(|(line, _)| line)
+			.collect();
+		Some((first_line, group))
+	})
+}
+
+pub fn input_path(inputs_dir: &Path, day: u8) -> PathBuf {
+	inputs_dir.join(format!("day{:02}.txt", day))
+}
+
+// Inputs downloaded by `aoc fetch` are picked up when no input is given.
+pub fn cached_input(day: u8) -> Option<PathBuf> {
+	Some(input_path(Path::new(INPUTS_DIR), day)).filter(|path| path.is_file())
+}
+
+pub fn read_to_string(filename: &str) -> Result<String> {
+	Input::File(PathBuf::from(filename)).read_to_string()
+}
+
+pub fn read_file(filename: &str) -> Result<Vec<String>> {
+	read_file_with(filename, Encoding::Strict)
+}
+
+pub fn read_file_with(filename: &str, encoding: Encoding) -> Result<Vec<String>> {
+	Input::File(PathBuf::from(filename)).read_lines_with(encoding)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_from_arg() {
+		assert_eq!(Input::from_arg("-"), Input::Stdin);
+		assert_eq!(Input::from_arg("input.txt"), Input::File(PathBuf::from("input.txt")));
+	}
+
+	#[test]
+	fn test_read_lines_from_text() {
+		let input = Input::Text("1000\n2000\n\n3000".to_string());
+		assert_eq!(input.read_lines().unwrap(), vec!["1000", "2000", "", "3000"]);
+	}
+
+	#[test]
+	fn test_split_groups() {
+		assert_eq!(
+			split_groups("\n1\n2\n\n3".lines()).collect::<Vec<_>>(),
+			vec![(2, vec!["1", "2"]), (5, vec!["3"])]
+		);
+		assert_eq!(
+			split_groups("1\n2\n\n\n3\n\n4\n5\n".lines()).collect::<Vec<_>>(),
+			vec![(1, vec!["1", "2"]), (5, vec!["3"]), (7, vec!["4", "5"])]
+		);
+		assert_eq!(split_groups("".lines()).count(), 0);
+	}
+
+	#[test]
+	fn test_read_to_string() {
+		let input = Input::Text("1\n2\n".to_string());
+		assert_eq!(input.read_to_string().unwrap(), "1\n2\n");
+	}
+
+	#[test]
+	fn test_normalize() {
+		assert_eq!(normalize("\u{feff}$ ls\r\ndir a  \r\n\r\n[]\t\n"), "$ ls\ndir a\n\n[]\n");
+		assert_eq!(normalize("    [D]\n"), "    [D]\n");
+	}
+
+	#[test]
+	fn test_lines_are_normalized() {
+		let input = Input::Text("\u{feff}dir a\r\n[] \r\n".to_string());
+		assert_eq!(input.read_lines().unwrap(), vec!["dir a", "[]"]);
+	}
+
+	#[test]
+	fn test_strict_and_lossy_utf8() {
+		let bytes = b"1\n2\n\xff3\n".to_vec();
+		assert!(matches!(
+			decode(bytes.clone(), Encoding::Strict, "input.txt"),
+			Err(Error::Utf8 { line: 3, .. })
+		));
+		assert_eq!(
+			decode(bytes, Encoding::Lossy, "input.txt").unwrap(),
+			"1\n2\n\u{fffd}3\n"
+		);
+	}
+
+	#[test]
+	fn test_read_file_keeps_path_in_error() {
+		match read_file("does/not/exist.txt") {
+			Err(Error::Io { path, .. }) => assert_eq!(path, PathBuf::from("does/not/exist.txt")),
+			_ => panic!("expected an I/O error"),
+		}
+	}
+}
@@ -0,0 +1,139 @@
+use crate::answers::Verdict;
+use crate::cli_opts::{Format, Options};
+use crate::solution::Part;
+use crate::Answer;
+use std::time::Duration;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PartResult {
+	pub day: u8,
+	pub part: Part,
+	pub answer: Answer,
+	pub elapsed: Duration,
+	pub verdict: Option<Verdict>,
+}
+
+pub(crate) fn json_string(text: &str) -> String {
+	let mut escaped = String::with_capacity(text.len() + 2);
+	escaped.push('"');
+	for character in text.chars() {
+		match character {
+			'"' => escaped.push_str("\\\""),
+			'\\' => escaped.push_str("\\\\"),
+			'\n' => escaped.push_str("\\n"),
+			'\r' => escaped.push_str("\\r"),
+			'\t' => escaped.push_str("\\t"),
+			c if (c as u32) < 0x20 => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+			c => escaped.push(c),
+		}
+	}
+	escaped.push('"');
+	escaped
+}
+
+pub(crate) fn print_labelled(label: &str, text: &str) {
+	if text.contains('\n') {
+		println!("{}:\n{}", label, text);
+	} else {
+		println!("{}: {}", label, text);
+	}
+}
+
+pub(crate) fn label(part: Part) -> &'static str {
+	match part {
+		Part::One => "Part one answer",
+		Part::Two => "Part two answer",
+	}
+}
+
+impl PartResult {
+	pub fn to_json(&self) -> String {
+		let check = match &self.verdict {
+			None => String::new(),
+			Some(Verdict::Pass) => ",\"check\":\"pass\"".to_string(),
+			Some(Verdict::Unknown) => ",\"check\":\"unknown\"".to_string(),
+			Some(Verdict::Fail { expected }) => {
+				format!(",\"check\":\"fail\",\"expected\":{}", expected.to_json())
+			}
+		};
+		format!(
+			"{{\"day\":{},\"part\":{},\"answer\":{},\"answer_type\":{},\"elapsed\":{}{}}}",
+			self.day,
+			self.part.number(),
+			self.answer.to_json(),
+			json_string(self.answer.kind()),
+			self.elapsed.as_secs_f64(),
+			check
+		)
+	}
+
+	pub fn print_text(&self, options: &Options) {
+		if options.quiet {
+			match self.verdict {
+				None => println!("{}", self.answer),
+				Some(Verdict::Pass) => println!("PASS"),
+				Some(Verdict::Fail { .. }) => println!("FAIL"),
+				Some(Verdict::Unknown) => println!("UNKNOWN"),
+			}
+			return;
+		}
+
+		let elapsed = match options.time {
+			true => format!(" ({:?})", self.elapsed),
+			false => String::new(),
+		};
+		let status = match self.verdict {
+			None => "",
+			Some(Verdict::Pass) => " PASS",
+			Some(Verdict::Fail { .. }) => " FAIL",
+			Some(Verdict::Unknown) => " UNKNOWN",
+		};
+		print_labelled(
+			&format!("{}{}{}", label(self.part), elapsed, status),
+			&self.answer.to_string(),
+		);
+		if let Some(Verdict::Fail { expected }) = &self.verdict {
+			print_labelled("Expected", &expected.to_string());
+		}
+	}
+
+	pub fn print(&self, options: &Options) {
+		match options.format {
+			Format::Json => println!("{}", self.to_json()),
+			Format::Text => self.print_text(options),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_json_string() {
+		assert_eq!(json_string("CMZ"), "\"CMZ\"");
+		assert_eq!(json_string("# .\n. #"), "\"# .\\n. #\"");
+		assert_eq!(json_string("say \"hi\"\\"), "\"say \\\"hi\\\"\\\\\"");
+	}
+
+	#[test]
+	fn test_to_json() {
+		let mut result = PartResult {
+			day: 5,
+			part: Part::Two,
+			answer: Answer::from("MCD"),
+			elapsed: Duration::from_millis(250),
+			verdict: None,
+		};
+		assert_eq!(
+			result.to_json(),
+			"{\"day\":5,\"part\":2,\"answer\":\"MCD\",\"answer_type\":\"text\",\"elapsed\":0.25}"
+		);
+		result.verdict = Some(Verdict::Fail {
+			expected: Answer::from("CMZ"),
+		});
+		assert!(result
+			.to_json()
+			.ends_with(",\"check\":\"fail\",\"expected\":\"CMZ\"}"));
+	}
+}
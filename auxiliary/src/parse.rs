@@ -0,0 +1,259 @@
+use crate::diagnostics::Fault;
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Expected {
+	Literal(&'static str),
+	Integer,
+	End,
+	OneOf(Vec<Expected>),
+}
+
+impl fmt::Display for Expected {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Expected::Literal(literal) => write!(f, "{:?}", literal),
+			Expected::Integer => write!(f, "an integer"),
+			Expected::End => write!(f, "end of line"),
+			Expected::OneOf(alternatives) => {
+				let alternatives = alternatives
+					.iter()
+					.map(Expected::to_string)
+					.collect::<Vec<String>>();
+				write!(f, "{}", alternatives.join(" or "))
+			}
+		}
+	}
+}
+
+// Parsers only ever hand back suffixes of their input, so `rest` is enough to
+// recover the position once `run` knows the whole text.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Failure<'a> {
+	pub rest: &'a str,
+	pub expected: Expected,
+}
+
+pub type PResult<'a, T> = Result<(T, &'a str), Failure<'a>>;
+
+pub trait Parser<'a, T>: Fn(&'a str) -> PResult<'a, T> {}
+
+impl<'a, T, P> Parser<'a, T> for P where P: Fn(&'a str) -> PResult<'a, T> {}
+
+fn fail<T>(rest: &str, expected: Expected) -> PResult<'_, T> {
+	Err(Failure { rest, expected })
+}
+
+pub fn literal<'a>(expected: &'static str) -> impl Parser<'a, &'a str> {
+	move |input: &'a str| match input.strip_prefix(expected) {
+		Some(rest) => Ok((&input[..expected.len()], rest)),
+		None => fail(input, Expected::Literal(expected)),
+	}
+}
+
+pub fn integer<T: FromStr>(input: &str) -> PResult<'_, T> {
+	let sign = usize::from(input.starts_with(['-', '+']));
+	let digits = input[sign..]
+		.find(|c: char| !c.is_ascii_digit())
+		.unwrap_or(input.len() - sign);
+	if digits == 0 {
+		return fail(input, Expected::Integer);
+	}
+	let (raw_integer, rest) = input.split_at(sign + digits);
+	match raw_integer.parse::<T>() {
+		Ok(integer) => Ok((integer, rest)),
+		Err(_) => fail(input, Expected::Integer),
+	}
+}
+
+pub fn whitespace(input: &str) -> PResult<'_, &str> {
+	let rest = input.trim_start_matches([' ', '\t']);
+	Ok((&input[..input.len() - rest.len()], rest))
+}
+
+pub fn map<'a, T, U>(parser: impl Parser<'a, T>, f: impl Fn(T) -> U) -> impl Parser<'a, U> {
+	move |input: &'a str| parser(input).map(|(value, rest)| (f(value), rest))
+}
+
+pub fn preceded<'a, T>(prefix: &'static str, parser: impl Parser<'a, T>) -> impl Parser<'a, T> {
+	move |input: &'a str| parser(literal(prefix)(input)?.1)
+}
+
+pub fn trimmed<'a, T>(parser: impl Parser<'a, T>) -> impl Parser<'a, T> {
+	move |input: &'a str| {
+		let (value, rest) = parser(whitespace(input)?.1)?;
+		Ok((value, whitespace(rest)?.1))
+	}
+}
+
+// Whichever alternative got further explains the failure best; when both
+// stopped at the same place their expectations are merged.
+pub fn or<'a, T>(first: impl Parser<'a, T>, second: impl Parser<'a, T>) -> impl Parser<'a, T> {
+	move |input: &'a str| {
+		let first_failure = match first(input) {
+			Ok(parsed) => return Ok(parsed),
+			Err(failure) => failure,
+		};
+		let second_failure = match second(input) {
+			Ok(parsed) => return Ok(parsed),
+			Err(failure) => failure,
+		};
+		match first_failure.rest.len().cmp(&second_failure.rest.len()) {
+			std::cmp::Ordering::Less => Err(first_failure),
+			std::cmp::Ordering::Greater => Err(second_failure),
+			std::cmp::Ordering::Equal => {
+				let mut expected = Vec::new();
+				for failure in [first_failure.expected, second_failure.expected] {
+					match failure {
+						Expected::OneOf(alternatives) => expected.extend(alternatives),
+						failure => expected.push(failure),
+					}
+				}
+				fail(input, Expected::OneOf(expected))
+			}
+		}
+	}
+}
+
+pub fn separated<'a, T>(item: impl Parser<'a, T>, separator: &'static str) -> impl Parser<'a, Vec<T>> {
+	move |input: &'a str| {
+		let (first, mut rest) = item(input)?;
+		let mut items = vec![first];
+		while let Some(after_separator) = rest.strip_prefix(separator) {
+			let (next, after_item) = item(after_separator)?;
+			items.push(next);
+			rest = after_item;
+		}
+		Ok((items, rest))
+	}
+}
+
+pub fn key_value<'a, T>(key: &'static str, value: impl Parser<'a, T>) -> impl Parser<'a, T> {
+	move |input: &'a str| {
+		let (_, rest) = literal(key)(input)?;
+		value(literal("=")(rest)?.1)
+	}
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+	pub offset: usize,
+	pub line: usize,
+	pub column: usize,
+	pub expected: Expected,
+}
+
+impl ParseError {
+	fn new(input: &str, failure: Failure) -> Self {
+		let offset = input.len() - failure.rest.len();
+		let before = &input[..offset];
+		ParseError {
+			offset,
+			line: before.matches('\n').count() + 1,
+			column: before.rsplit('\n').next().unwrap_or("").chars().count() + 1,
+			expected: failure.expected,
+		}
+	}
+
+	// `input` has to be the text handed to `run`; the fault points at the word
+	// found where the expectation failed.
+	pub fn fault<'a>(&self, input: &'a str) -> Fault<'a> {
+		let rest = &input[self.offset..];
+		let is_word = |c: char| c.is_alphanumeric() || c == '-' || c == '_';
+		let length = match rest.chars().next() {
+			None => 0,
+			Some(c) if is_word(c) => rest.find(|c: char| !is_word(c)).unwrap_or(rest.len()),
+			Some(c) => c.len_utf8(),
+		};
+		match length {
+			0 => Fault::new(&rest[..0], format!("expected {}, found end of line", self.expected)),
+			length => Fault::new(
+				&rest[..length],
+				format!("expected {}, found {:?}", self.expected, &rest[..length]),
+			),
+		}
+	}
+}
+
+impl fmt::Display for ParseError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(
+			f,
+			"expected {} at line {}, column {}",
+			self.expected, self.line, self.column
+		)
+	}
+}
+
+pub fn run<'a, T>(parser: impl Parser<'a, T>, input: &'a str) -> Result<T, ParseError> {
+	match parser(input) {
+		Ok((value, "")) => Ok(value),
+		Ok((_, rest)) => Err(ParseError::new(input, Failure {
+			rest,
+			expected: Expected::End,
+		})),
+		Err(failure) => Err(ParseError::new(input, failure)),
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn point(input: &str) -> PResult<'_, (i64, i64)> {
+		let (x, rest) = key_value("x", integer)(input)?;
+		let (y, rest) = preceded(", ", key_value("y", integer))(rest)?;
+		Ok(((x, y), rest))
+	}
+
+	#[test]
+	fn test_primitives() {
+		assert_eq!(integer::<i32>("-42 rest"), Ok((-42, " rest")));
+		assert_eq!(
+			integer::<u8>("300"),
+			Err(Failure {
+				rest: "300",
+				expected: Expected::Integer
+			})
+		);
+		assert_eq!(whitespace("  \tx"), Ok(("  \t", "x")));
+		assert_eq!(literal("move")("move 1"), Ok(("move", " 1")));
+		assert_eq!(run(trimmed(integer::<u8>), "  7 "), Ok(7));
+	}
+
+	#[test]
+	fn test_combinators() {
+		assert_eq!(run(point, "x=-2, y=15"), Ok((-2, 15)));
+		assert_eq!(
+			run(separated(integer::<u32>, " -> "), "498 -> 4 -> 6"),
+			Ok(vec![498, 4, 6])
+		);
+		let operand = or(map(literal("old"), |_| None), map(integer::<u64>, Some));
+		assert_eq!(run(&operand, "old"), Ok(None));
+		assert_eq!(run(&operand, "19"), Ok(Some(19)));
+		assert_eq!(
+			run(&operand, "new").unwrap_err().expected,
+			Expected::OneOf(vec![Expected::Literal("old"), Expected::Integer])
+		);
+	}
+
+	#[test]
+	fn test_error_positions() {
+		let error = run(point, "x=3, y=a").unwrap_err();
+		assert_eq!((error.offset, error.line, error.column), (7, 1, 8));
+		assert_eq!(error.expected, Expected::Integer);
+		assert_eq!(error.to_string(), "expected an integer at line 1, column 8");
+		assert_eq!(
+			error.fault("x=3, y=a"),
+			Fault::new("a", "expected an integer, found \"a\"")
+		);
+
+		let error = run(separated(integer::<u8>, ","), "1,2\n3").unwrap_err();
+		assert_eq!((error.line, error.column, error.expected), (1, 4, Expected::End));
+		assert_eq!(
+			run(point, "x=3").unwrap_err().fault("x=3"),
+			Fault::after("x=3", "expected \", \", found end of line")
+		);
+	}
+}
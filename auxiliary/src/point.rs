@@ -0,0 +1,252 @@
+use crate::direction::{ALL, CARDINAL};
+use std::ops::{Add, Sub};
+
+pub trait Coordinate: Copy + Ord + Add<Output = Self> + Sub<Output = Self> {
+	const ZERO: Self;
+	const ONE: Self;
+
+	fn checked_add(self, other: Self) -> Option<Self>;
+	fn checked_sub(self, other: Self) -> Option<Self>;
+
+	// Unlike the standard `abs_diff` this stays in `Self`, so distances of
+	// signed and unsigned points can be added up the same way.
+	fn distance(self, other: Self) -> Self {
+		match self > other {
+			true => self - other,
+			false => other - self,
+		}
+	}
+}
+
+macro_rules! coordinates {
+	($($integer:ty),*) => {
+		$(impl Coordinate for $integer {
+			const ZERO: Self = 0;
+			const ONE: Self = 1;
+
+			fn checked_add(self, other: Self) -> Option<Self> {
+				<$integer>::checked_add(self, other)
+			}
+
+			fn checked_sub(self, other: Self) -> Option<Self> {
+				<$integer>::checked_sub(self, other)
+			}
+		})*
+	};
+}
+
+coordinates!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+fn offset<T: Coordinate>(value: T, delta: i8) -> Option<T> {
+	match delta {
+		-1 => value.checked_sub(T::ONE),
+		1 => value.checked_add(T::ONE),
+		_ => Some(value),
+	}
+}
+
+fn signum<T: Coordinate>(value: T) -> T {
+	match value.cmp(&T::ZERO) {
+		std::cmp::Ordering::Less => T::ZERO - T::ONE,
+		std::cmp::Ordering::Equal => T::ZERO,
+		std::cmp::Ordering::Greater => T::ONE,
+	}
+}
+
+const SIX_WAY: [(i8, i8, i8); 6] = [
+	(1, 0, 0),
+	(-1, 0, 0),
+	(0, 1, 0),
+	(0, -1, 0),
+	(0, 0, 1),
+	(0, 0, -1),
+];
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Point2<T> {
+	pub x: T,
+	pub y: T,
+}
+
+impl<T: Coordinate> Point2<T> {
+	pub const fn new(x: T, y: T) -> Self {
+		Point2 { x, y }
+	}
+
+	pub fn manhattan(self, other: Self) -> T {
+		self.x.distance(other.x) + self.y.distance(other.y)
+	}
+
+	pub fn chebyshev(self, other: Self) -> T {
+		self.x.distance(other.x).max(self.y.distance(other.y))
+	}
+
+	pub fn signum(self) -> Self {
+		Point2::new(signum(self.x), signum(self.y))
+	}
+
+	pub(crate) fn offset(self, (dx, dy): (i8, i8)) -> Option<Self> {
+		Some(Point2::new(offset(self.x, dx)?, offset(self.y, dy)?))
+	}
+
+	// Neighbours that would leave the range of `T` are skipped, so unsigned
+	// points on an edge simply have fewer of them.
+	pub fn neighbours4(self) -> impl Iterator<Item = Self> {
+		CARDINAL.into_iter().filter_map(move |direction| direction.apply(self))
+	}
+
+	pub fn neighbours8(self) -> impl Iterator<Item = Self> {
+		ALL.into_iter().filter_map(move |direction| direction.apply(self))
+	}
+
+	pub fn within(self, min: Self, max: Self) -> bool {
+		(min.x..=max.x).contains(&self.x) && (min.y..=max.y).contains(&self.y)
+	}
+
+	pub fn bounding_box(points: impl IntoIterator<Item = Self>) -> Option<(Self, Self)> {
+		points.into_iter().fold(None, |bounds, point| match bounds {
+			None => Some((point, point)),
+			Some((min, max)) => Some((
+				Point2::new(min.x.min(point.x), min.y.min(point.y)),
+				Point2::new(max.x.max(point.x), max.y.max(point.y)),
+			)),
+		})
+	}
+}
+
+impl<T: Coordinate> Add for Point2<T> {
+	type Output = Self;
+
+	fn add(self, other: Self) -> Self {
+		Point2::new(self.x + other.x, self.y + other.y)
+	}
+}
+
+impl<T: Coordinate> Sub for Point2<T> {
+	type Output = Self;
+
+	fn sub(self, other: Self) -> Self {
+		Point2::new(self.x - other.x, self.y - other.y)
+	}
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Point3<T> {
+	pub x: T,
+	pub y: T,
+	pub z: T,
+}
+
+impl<T: Coordinate> Point3<T> {
+	pub const fn new(x: T, y: T, z: T) -> Self {
+		Point3 { x, y, z }
+	}
+
+	pub fn manhattan(self, other: Self) -> T {
+		self.x.distance(other.x) + self.y.distance(other.y) + self.z.distance(other.z)
+	}
+
+	pub fn chebyshev(self, other: Self) -> T {
+		self.x
+			.distance(other.x)
+			.max(self.y.distance(other.y))
+			.max(self.z.distance(other.z))
+	}
+
+	pub fn signum(self) -> Self {
+		Point3::new(signum(self.x), signum(self.y), signum(self.z))
+	}
+
+	fn offset(self, (dx, dy, dz): (i8, i8, i8)) -> Option<Self> {
+		Some(Point3::new(
+			offset(self.x, dx)?,
+			offset(self.y, dy)?,
+			offset(self.z, dz)?,
+		))
+	}
+
+	pub fn neighbours6(self) -> impl Iterator<Item = Self> {
+		SIX_WAY.into_iter().filter_map(move |delta| self.offset(delta))
+	}
+
+	pub fn neighbours26(self) -> impl Iterator<Item = Self> {
+		(-1..=1)
+			.flat_map(|dx| (-1..=1).flat_map(move |dy| (-1..=1).map(move |dz| (dx, dy, dz))))
+			.filter(|&delta| delta != (0, 0, 0))
+			.filter_map(move |delta| self.offset(delta))
+	}
+
+	pub fn within(self, min: Self, max: Self) -> bool {
+		(min.x..=max.x).contains(&self.x)
+			&& (min.y..=max.y).contains(&self.y)
+			&& (min.z..=max.z).contains(&self.z)
+	}
+
+	pub fn bounding_box(points: impl IntoIterator<Item = Self>) -> Option<(Self, Self)> {
+		points.into_iter().fold(None, |bounds, point| match bounds {
+			None => Some((point, point)),
+			Some((min, max)) => Some((
+				Point3::new(min.x.min(point.x), min.y.min(point.y), min.z.min(point.z)),
+				Point3::new(max.x.max(point.x), max.y.max(point.y), max.z.max(point.z)),
+			)),
+		})
+	}
+}
+
+impl<T: Coordinate> Add for Point3<T> {
+	type Output = Self;
+
+	fn add(self, other: Self) -> Self {
+		Point3::new(self.x + other.x, self.y + other.y, self.z + other.z)
+	}
+}
+
+impl<T: Coordinate> Sub for Point3<T> {
+	type Output = Self;
+
+	fn sub(self, other: Self) -> Self {
+		Point3::new(self.x - other.x, self.y - other.y, self.z - other.z)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_arithmetic_and_distances() {
+		let p = Point2::new(8, 7);
+		let q = Point2::new(2, 10);
+		assert_eq!(p + q, Point2::new(10, 17));
+		assert_eq!(q - p, Point2::new(-6, 3));
+		assert_eq!((q - p).signum(), Point2::new(-1, 1));
+		assert_eq!(p.manhattan(q), 9);
+		assert_eq!(p.chebyshev(q), 6);
+		assert_eq!(Point2::<usize>::new(1, 5).manhattan(Point2::new(4, 2)), 6);
+		assert_eq!(Point3::new(1, 2, 3).manhattan(Point3::new(2, 2, 1)), 3);
+	}
+
+	#[test]
+	fn test_neighbours() {
+		assert_eq!(Point2::new(0, 0).neighbours4().count(), 4);
+		assert_eq!(Point2::new(0, 0).neighbours8().count(), 8);
+		assert_eq!(
+			Point2::<usize>::new(0, 3).neighbours4().collect::<Vec<_>>(),
+			vec![Point2::new(0, 2), Point2::new(1, 3), Point2::new(0, 4)]
+		);
+		let origin = Point3::new(0, 0, 0);
+		assert!(origin.neighbours6().all(|point| point.manhattan(origin) == 1));
+		assert_eq!(origin.neighbours26().count(), 26);
+		assert!(origin.neighbours26().all(|point| point.chebyshev(origin) == 1));
+	}
+
+	#[test]
+	fn test_bounding_box() {
+		let points = [Point2::new(2, 18), Point2::new(-2, 15), Point2::new(9, 16)];
+		let (min, max) = Point2::bounding_box(points).unwrap();
+		assert_eq!((min, max), (Point2::new(-2, 15), Point2::new(9, 18)));
+		assert!(Point2::new(0, 16).within(min, max));
+		assert!(!Point2::new(0, 19).within(min, max));
+		assert_eq!(Point3::<i32>::bounding_box([]), None);
+	}
+}
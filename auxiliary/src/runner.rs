@@ -0,0 +1,128 @@
+use crate::answer::IntoAnswer;
+use crate::answers::{Answers, Verdict};
+use crate::bench::{self, BenchReport};
+use crate::cli_opts::{self, Format, Options};
+use crate::io_::Input;
+use crate::output::PartResult;
+use crate::solution::{Part, Solution};
+use crate::watch;
+use crate::{Answer, Error, Result};
+use std::process::exit;
+use std::time::{Duration, Instant};
+
+pub type Runner = fn(&str, Option<Part>) -> Result<Vec<PartResult>>;
+pub type Bencher = fn(&Input, &Options) -> Result<BenchReport>;
+
+#[derive(Clone, Copy)]
+pub struct Day {
+	pub number: u8,
+	pub solve: Runner,
+	pub bench: Bencher,
+}
+
+impl Day {
+	pub const fn of<S: Solution>() -> Day {
+		Day {
+			number: S::DAY,
+			solve: solve::<S>,
+			bench: bench::bench::<S>,
+		}
+	}
+}
+
+fn timed<T>(solve: impl FnOnce() -> T) -> (T, Duration) {
+	let start = Instant::now();
+	let result = solve();
+	(result, start.elapsed())
+}
+
+pub fn solve<S: Solution>(input: &str, part: Option<Part>) -> Result<Vec<PartResult>> {
+	let model = S::parse(input)?;
+	let mut results = Vec::new();
+	let mut finish = |part: Part, answer: Answer, elapsed: Duration| {
+		results.push(PartResult {
+			day: S::DAY,
+			part,
+			answer,
+			elapsed,
+			verdict: None,
+		})
+	};
+
+	if part != Some(Part::Two) {
+		let (answer, elapsed) = timed(|| S::part_one(&model));
+		finish(Part::One, answer.into_answer()?, elapsed);
+	}
+	if part != Some(Part::One) {
+		let (answer, elapsed) = timed(|| S::part_two(&model));
+		finish(Part::Two, answer.into_answer()?, elapsed);
+	}
+	Ok(results)
+}
+
+pub fn bench_inputs(day: Day, options: &Options) -> Result<Vec<BenchReport>> {
+	let mut reports = Vec::new();
+	for input in &options.inputs {
+		let report = (day.bench)(input, options)?;
+		match options.format {
+			Format::Json => println!("{}", report.to_json()),
+			Format::Text => println!("{}", report),
+		}
+		reports.push(report);
+	}
+	Ok(reports)
+}
+
+pub fn write_report(options: &Options, reports: &[BenchReport]) -> Result<()> {
+	match &options.report {
+		Some(path) => bench::write_report(path, reports),
+		None => Ok(()),
+	}
+}
+
+pub fn run_inputs(day: Day, options: &Options) -> Result<()> {
+	if options.bench.is_some() {
+		return write_report(options, &bench_inputs(day, options)?);
+	}
+	if options.watch {
+		return watch::watch(day, options);
+	}
+
+	let mut failed = 0;
+	for input in &options.inputs {
+		if options.inputs.len() > 1 && options.format == Format::Text && !options.quiet {
+			println!("{}", input);
+		}
+		let answers = match options.check {
+			true => Some(Answers::for_input(input)?),
+			false => None,
+		};
+		let results = (day.solve)(&input.read_with(options.encoding)?, options.part)
+			.map_err(|error| error.with_input(&input.to_string()))?;
+		for mut result in results {
+			if let Some(answers) = &answers {
+				let verdict = answers.verify(result.part, &result.answer);
+				if let Verdict::Fail { .. } = verdict {
+					failed += 1;
+				}
+				result.verdict = Some(verdict);
+			}
+			result.print(options);
+		}
+	}
+
+	match failed {
+		0 => Ok(()),
+		failed => Err(Error::CheckFailed(failed)),
+	}
+}
+
+pub fn main<S: Solution>() {
+	let result =
+		cli_opts::provided_options(S::DAY).and_then(|options| run_inputs(Day::of::<S>(), &options));
+
+	if let Err(error) = result {
+		eprintln!("{}", error);
+		exit(1);
+	}
+}
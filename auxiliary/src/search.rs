@@ -0,0 +1,245 @@
+use std::cmp::Ordering;
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+// Everything a search learned: the cost of reaching each visited node and the
+// node it was reached from, which is enough to rebuild any shortest path.
+#[derive(Clone, Debug)]
+pub struct Paths<N, C> {
+	pub distances: HashMap<N, C>,
+	pub predecessors: HashMap<N, N>,
+}
+
+impl<N: Copy + Eq + Hash, C: Copy> Paths<N, C> {
+	fn new() -> Self {
+		Paths {
+			distances: HashMap::new(),
+			predecessors: HashMap::new(),
+		}
+	}
+
+	pub fn contains(&self, node: N) -> bool {
+		self.distances.contains_key(&node)
+	}
+
+	pub fn distance(&self, node: N) -> Option<C> {
+		self.distances.get(&node).copied()
+	}
+
+	pub fn path_to(&self, goal: N) -> Option<Vec<N>> {
+		self.distances.get(&goal)?;
+		let mut path = vec![goal];
+		let mut node = goal;
+		while let Some(&previous) = self.predecessors.get(&node) {
+			path.push(previous);
+			node = previous;
+		}
+		path.reverse();
+		Some(path)
+	}
+}
+
+pub fn multi_source_bfs<N, I>(
+	starts: impl IntoIterator<Item = N>,
+	mut successors: impl FnMut(N) -> I,
+) -> Paths<N, usize>
+where
+	N: Copy + Eq + Hash,
+	I: IntoIterator<Item = N>,
+{
+	let mut paths = Paths::new();
+	let mut queue = VecDeque::new();
+	for start in starts {
+		if paths.distances.insert(start, 0).is_none() {
+			queue.push_back(start);
+		}
+	}
+
+	while let Some(node) = queue.pop_front() {
+		let distance = paths.distances[&node];
+		for next in successors(node) {
+			if let Entry::Vacant(entry) = paths.distances.entry(next) {
+				entry.insert(distance + 1);
+				paths.predecessors.insert(next, node);
+				queue.push_back(next);
+			}
+		}
+	}
+	paths
+}
+
+pub fn bfs<N, I>(start: N, successors: impl FnMut(N) -> I) -> Paths<N, usize>
+where
+	N: Copy + Eq + Hash,
+	I: IntoIterator<Item = N>,
+{
+	multi_source_bfs([start], successors)
+}
+
+// The filled region is every node in `distances`; like any other search it
+// also records how each node was reached.
+pub fn flood_fill<N, I>(
+	starts: impl IntoIterator<Item = N>,
+	successors: impl FnMut(N) -> I,
+) -> Paths<N, usize>
+where
+	N: Copy + Eq + Hash,
+	I: IntoIterator<Item = N>,
+{
+	multi_source_bfs(starts, successors)
+}
+
+struct Frontier<N, C> {
+	priority: C,
+	node: N,
+}
+
+impl<N, C: Ord> PartialEq for Frontier<N, C> {
+	fn eq(&self, other: &Self) -> bool {
+		self.priority == other.priority
+	}
+}
+
+impl<N, C: Ord> Eq for Frontier<N, C> {}
+
+impl<N, C: Ord> PartialOrd for Frontier<N, C> {
+	fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+		Some(self.cmp(other))
+	}
+}
+
+// Reversed, so the standard max-heap pops the cheapest node first.
+impl<N, C: Ord> Ord for Frontier<N, C> {
+	fn cmp(&self, other: &Self) -> Ordering {
+		other.priority.cmp(&self.priority)
+	}
+}
+
+fn best_first<N, C, I>(
+	start: N,
+	goal: Option<N>,
+	mut successors: impl FnMut(N) -> I,
+	mut heuristic: impl FnMut(N) -> C,
+) -> Paths<N, C>
+where
+	N: Copy + Eq + Hash,
+	C: Copy + Ord + Add<Output = C> + Default,
+	I: IntoIterator<Item = (N, C)>,
+{
+	let mut paths = Paths::new();
+	let mut settled = HashSet::new();
+	let mut frontier = BinaryHeap::from([Frontier {
+		priority: heuristic(start),
+		node: start,
+	}]);
+	paths.distances.insert(start, C::default());
+
+	while let Some(Frontier { node, .. }) = frontier.pop() {
+		if !settled.insert(node) {
+			continue;
+		}
+		if goal == Some(node) {
+			break;
+		}
+		let cost = paths.distances[&node];
+		for (next, step) in successors(node) {
+			let next_cost = cost + step;
+			if paths.distance(next).is_some_and(|known| known <= next_cost) {
+				continue;
+			}
+			paths.distances.insert(next, next_cost);
+			paths.predecessors.insert(next, node);
+			frontier.push(Frontier {
+				priority: next_cost + heuristic(next),
+				node: next,
+			});
+		}
+	}
+	paths
+}
+
+pub fn dijkstra<N, C, I>(start: N, successors: impl FnMut(N) -> I) -> Paths<N, C>
+where
+	N: Copy + Eq + Hash,
+	C: Copy + Ord + Add<Output = C> + Default,
+	I: IntoIterator<Item = (N, C)>,
+{
+	best_first(start, None, successors, |_| C::default())
+}
+
+// Stops as soon as `goal` is settled, so only the goal's distance and path are
+// guaranteed to be optimal. The heuristic must never overestimate.
+pub fn astar<N, C, I>(
+	start: N,
+	goal: N,
+	successors: impl FnMut(N) -> I,
+	heuristic: impl FnMut(N) -> C,
+) -> Paths<N, C>
+where
+	N: Copy + Eq + Hash,
+	C: Copy + Ord + Add<Output = C> + Default,
+	I: IntoIterator<Item = (N, C)>,
+{
+	best_first(start, Some(goal), successors, heuristic)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::point::Point2;
+
+	type Point = Point2<i32>;
+
+	// A 5x5 room with a wall along x=2 that is open only at y=4.
+	fn open(point: Point) -> bool {
+		point.within(Point2::new(0, 0), Point2::new(4, 4)) && (point.x != 2 || point.y == 4)
+	}
+
+	fn steps(point: Point) -> impl Iterator<Item = Point> {
+		point.neighbours4().filter(|&next| open(next))
+	}
+
+	#[test]
+	fn test_bfs() {
+		let paths = bfs(Point2::new(0, 0), steps);
+		let goal = Point2::new(4, 0);
+		assert_eq!(paths.distance(goal), Some(12));
+		let path = paths.path_to(goal).unwrap();
+		assert_eq!(path.len(), 13);
+		assert_eq!((path[0], path[12]), (Point2::new(0, 0), goal));
+		assert!(path.windows(2).all(|step| step[0].manhattan(step[1]) == 1));
+		assert_eq!(paths.path_to(Point2::new(2, 0)), None);
+
+		let paths = multi_source_bfs([Point2::new(0, 0), Point2::new(4, 4)], steps);
+		assert_eq!(paths.distance(goal), Some(4));
+		let filled = flood_fill([Point2::new(0, 0)], steps);
+		assert_eq!(filled.distances.len(), 21);
+		assert!(filled.contains(Point2::new(4, 0)) && !filled.contains(Point2::new(2, 0)));
+		assert_eq!(filled.path_to(Point2::new(0, 2)).map(|path| path.len()), Some(3));
+	}
+
+	#[test]
+	fn test_weighted() {
+		let edges = HashMap::from([
+			('a', vec![('b', 7), ('c', 2)]),
+			('c', vec![('b', 3), ('d', 8)]),
+			('b', vec![('d', 1)]),
+		]);
+		let successors = |node| edges.get(&node).cloned().unwrap_or_default();
+		let paths = dijkstra('a', successors);
+		assert_eq!(paths.distance('d'), Some(6));
+		assert_eq!(paths.path_to('d'), Some(vec!['a', 'c', 'b', 'd']));
+
+		let goal = Point2::new(4, 0);
+		let paths = astar(
+			Point2::new(0, 0),
+			goal,
+			|point| steps(point).map(|next| (next, 1)),
+			|point: Point| point.manhattan(goal),
+		);
+		assert_eq!(paths.distance(goal), Some(12));
+		assert_eq!(paths.path_to(goal).unwrap().len(), 13);
+	}
+}
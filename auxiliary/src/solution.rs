@@ -0,0 +1,37 @@
+use crate::answer::IntoAnswer;
+use crate::Result;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Part {
+	One,
+	Two,
+}
+
+impl Part {
+	pub fn from_number(number: u8) -> Option<Part> {
+		match number {
+			1 => Some(Part::One),
+			2 => Some(Part::Two),
+			_ => None,
+		}
+	}
+
+	pub fn number(self) -> u8 {
+		match self {
+			Part::One => 1,
+			Part::Two => 2,
+		}
+	}
+}
+
+pub trait Solution {
+	const DAY: u8;
+
+	type Model;
+	type PartOne: IntoAnswer;
+	type PartTwo: IntoAnswer;
+
+	fn parse(input: &str) -> Result<Self::Model>;
+	fn part_one(model: &Self::Model) -> Self::PartOne;
+	fn part_two(model: &Self::Model) -> Self::PartTwo;
+}
@@ -0,0 +1,475 @@
+use crate::client::Client;
+use crate::solution::Part;
+use crate::toml_;
+use crate::{Answer, Error, Result};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use toml::{Spanned, Table, Value};
+
+pub const HISTORY_FILE: &str = "inputs/submissions.toml";
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Outcome {
+	Correct,
+	TooHigh,
+	TooLow,
+	Incorrect,
+	Wait,
+	AlreadySolved,
+	Unknown,
+}
+
+// The rate-limit message counts down in "4m 32s" form.
+fn wait_duration(response: &str) -> Option<Duration> {
+	let (_, rest) = response.split_once("You have ")?;
+	let (raw_wait, _) = rest.split_once(" left to wait")?;
+	raw_wait.split_whitespace().try_fold(Duration::ZERO, |total, part| {
+		let (amount, unit) = part.split_at(part.find(|c: char| !c.is_ascii_digit())?);
+		let amount = amount.parse::<u64>().ok()?;
+		match unit {
+			"h" => Some(total + Duration::from_secs(amount * 3600)),
+			"m" => Some(total + Duration::from_secs(amount * 60)),
+			"s" => Some(total + Duration::from_secs(amount)),
+			_ => None,
+		}
+	})
+}
+
+// Wrong answers instead spell out a cooldown, as in "Please wait one minute
+// before trying again" or "please wait 5 minutes before trying again".
+fn cooldown(response: &str) -> Option<Duration> {
+	let response = response.to_lowercase();
+	let (_, rest) = response.split_once("please wait ")?;
+	let mut words = rest.split_whitespace();
+	let amount = match words.next()? {
+		"a" | "an" | "one" => 1,
+		"two" => 2,
+		"three" => 3,
+		"four" => 4,
+		"five" => 5,
+		"ten" => 10,
+		amount => amount.parse::<u64>().ok()?,
+	};
+	match words.next()?.trim_end_matches('s') {
+		"second" => Some(Duration::from_secs(amount)),
+		"minute" => Some(Duration::from_secs(amount * 60)),
+		"hour" => Some(Duration::from_secs(amount * 3600)),
+		_ => None,
+	}
+}
+
+impl Outcome {
+	// Wrong answers also mention waiting before the next attempt, so they are
+	// matched before the rate-limit message.
+	pub fn parse(response: &str) -> Outcome {
+		if response.contains("That's the right answer") {
+			Outcome::Correct
+		} else if response.contains("That's not the right answer") {
+			if response.contains("too high") {
+				Outcome::TooHigh
+			} else if response.contains("too low") {
+				Outcome::TooLow
+			} else {
+				Outcome::Incorrect
+			}
+		} else if response.contains("You gave an answer too recently") {
+			Outcome::Wait
+		} else if response.contains("You don't seem to be solving the right level") {
+			Outcome::AlreadySolved
+		} else {
+			Outcome::Unknown
+		}
+	}
+
+	pub fn name(&self) -> &'static str {
+		match self {
+			Outcome::Correct => "correct",
+			Outcome::TooHigh => "too high",
+			Outcome::TooLow => "too low",
+			Outcome::Incorrect => "incorrect",
+			Outcome::Wait => "wait",
+			Outcome::AlreadySolved => "already solved",
+			Outcome::Unknown => "unknown",
+		}
+	}
+
+	fn from_name(name: &str) -> Option<Outcome> {
+		match name {
+			"correct" => Some(Outcome::Correct),
+			"too high" => Some(Outcome::TooHigh),
+			"too low" => Some(Outcome::TooLow),
+			"incorrect" => Some(Outcome::Incorrect),
+			"wait" => Some(Outcome::Wait),
+			"already solved" => Some(Outcome::AlreadySolved),
+			"unknown" => Some(Outcome::Unknown),
+			_ => None,
+		}
+	}
+
+	pub fn is_wrong(&self) -> bool {
+		matches!(self, Outcome::TooHigh | Outcome::TooLow | Outcome::Incorrect)
+	}
+}
+
+impl fmt::Display for Outcome {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Outcome::Correct => write!(f, "That's the right answer!"),
+			Outcome::TooHigh => write!(f, "That's not the right answer; it is too high."),
+			Outcome::TooLow => write!(f, "That's not the right answer; it is too low."),
+			Outcome::Incorrect => write!(f, "That's not the right answer."),
+			Outcome::Wait => write!(f, "Answered too recently."),
+			Outcome::AlreadySolved => write!(f, "This part is already solved."),
+			Outcome::Unknown => write!(f, "Unrecognised response."),
+		}
+	}
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Response {
+	pub outcome: Outcome,
+	pub wait: Option<Duration>,
+}
+
+impl Response {
+	pub fn parse(response: &str) -> Response {
+		Response {
+			outcome: Outcome::parse(response),
+			wait: wait_duration(response).or_else(|| cooldown(response)),
+		}
+	}
+}
+
+impl fmt::Display for Response {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "{}", self.outcome)?;
+		match self.wait {
+			Some(wait) => write!(f, " Wait {}s before submitting again.", wait.as_secs()),
+			None => Ok(()),
+		}
+	}
+}
+
+// `wait` is the cooldown the server imposed after this submission, whatever
+// its outcome.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Submission {
+	pub day: u8,
+	pub part: Part,
+	pub answer: Answer,
+	pub outcome: Outcome,
+	pub wait: Option<Duration>,
+	pub at: u64,
+}
+
+impl Submission {
+	fn to_toml(&self) -> String {
+		let mut entry = format!(
+			"[[submission]]\nday = {}\npart = {}\nanswer = {}\noutcome = {}\nat = {}\n",
+			self.day,
+			self.part.number(),
+			Value::String(self.answer.to_string()),
+			Value::String(self.outcome.name().to_string()),
+			self.at
+		);
+		if let Some(wait) = self.wait {
+			entry.push_str(&format!("wait = {}\n", wait.as_secs()));
+		}
+		entry
+	}
+
+	fn from_toml(table: &Table) -> Option<Submission> {
+		let integer = |key| table.get(key).and_then(Value::as_integer);
+		let wait = match table.get("wait") {
+			None => None,
+			Some(_) => Some(Duration::from_secs(u64::try_from(integer("wait")?).ok()?)),
+		};
+		Some(Submission {
+			day: u8::try_from(integer("day")?).ok()?,
+			part: Part::from_number(u8::try_from(integer("part")?).ok()?)?,
+			answer: Answer::parse(table.get("answer")?.as_str()?),
+			outcome: Outcome::from_name(table.get("outcome")?.as_str()?)?,
+			wait,
+			at: u64::try_from(integer("at")?).ok()?,
+		})
+	}
+}
+
+pub fn now() -> u64 {
+	SystemTime::now()
+		.duration_since(UNIX_EPOCH)
+		.map_or(0, |elapsed| elapsed.as_secs())
+}
+
+// Images have to be read by a person, so they are never submitted automatically.
+pub fn submit(
+	client: &Client,
+	history: &mut History,
+	day: u8,
+	part: Part,
+	answer: &Answer,
+) -> Result<Response> {
+	if let Answer::Image(_) = answer {
+		return Err(Error::Submission(format!(
+			"Day {} part {} renders an image; read it and submit the letters by hand.",
+			day,
+			part.number()
+		)));
+	}
+	history.check(day, part, answer, now())?;
+
+	let response = client.submit(day, part, answer)?;
+	history.record(Submission {
+		day,
+		part,
+		answer: answer.clone(),
+		outcome: response.outcome.clone(),
+		wait: response.wait,
+		at: now(),
+	})?;
+	Ok(response)
+}
+
+pub struct History {
+	path: PathBuf,
+	submissions: Vec<Submission>,
+}
+
+impl History {
+	pub fn load(path: &Path) -> Result<History> {
+		let text = match fs::read_to_string(path) {
+			Ok(text) => text,
+			Err(error) if error.kind() == io::ErrorKind::NotFound => String::new(),
+			Err(error) => return Err(Error::io(path, error)),
+		};
+		let mut document = toml_::parse::<BTreeMap<String, Vec<Spanned<Table>>>>(path, &text)?;
+		let submissions = document
+			.remove("submission")
+			.unwrap_or_default()
+			.iter()
+			.enumerate()
+			.map(|(idx, entry)| {
+				Submission::from_toml(entry.get_ref()).ok_or_else(|| {
+					toml_::invalid(path, &text, entry, format!("invalid submission #{}", idx + 1))
+				})
+			})
+			.collect::<Result<Vec<Submission>>>()?;
+
+		Ok(History {
+			path: path.to_path_buf(),
+			submissions,
+		})
+	}
+
+	pub fn submissions(&self) -> &[Submission] {
+		&self.submissions
+	}
+
+	// Besides exact repeats, a number past a known too-high or too-low bound
+	// is just as certainly wrong.
+	pub fn check(&self, day: u8, part: Part, answer: &Answer, now: u64) -> Result<()> {
+		let mut previous = self
+			.submissions
+			.iter()
+			.filter(|submission| submission.day == day && submission.part == part);
+
+		if let Some(wait) = self.wait_remaining(now) {
+			return Err(Error::Submission(format!(
+				"Answered too recently; wait {}s before submitting again.",
+				wait.as_secs()
+			)));
+		}
+		if previous.clone().any(|submission| submission.outcome == Outcome::Correct) {
+			return Err(Error::Submission(format!(
+				"Day {} part {} is already solved.",
+				day,
+				part.number()
+			)));
+		}
+		let known_wrong = previous.find(|submission| match (&submission.answer, answer) {
+			_ if submission.answer == *answer => submission.outcome.is_wrong(),
+			(Answer::Integer(known), Answer::Integer(new)) => match submission.outcome {
+				Outcome::TooHigh => new >= known,
+				Outcome::TooLow => new <= known,
+				_ => false,
+			},
+			_ => false,
+		});
+		match known_wrong {
+			Some(submission) => Err(Error::Submission(format!(
+				"Refusing to submit {}: {} was already rejected as {}.",
+				answer,
+				submission.answer,
+				submission.outcome.name()
+			))),
+			None => Ok(()),
+		}
+	}
+
+	pub fn wait_remaining(&self, now: u64) -> Option<Duration> {
+		let last = self.submissions.last()?;
+		Some(last.at + last.wait?.as_secs())
+			.filter(|&until| until > now)
+			.map(|until| Duration::from_secs(until - now))
+	}
+
+	pub fn record(&mut self, submission: Submission) -> Result<()> {
+		if let Some(parent) = self.path.parent() {
+			fs::create_dir_all(parent).map_err(|source| Error::io(parent, source))?;
+		}
+		OpenOptions::new()
+			.create(true)
+			.append(true)
+			.open(&self.path)
+			.and_then(|mut file| writeln!(file, "{}", submission.to_toml()))
+			.map_err(|source| Error::io(&self.path, source))?;
+		self.submissions.push(submission);
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::client::tests::temp_dir;
+
+	fn submission(answer: i32, outcome: Outcome) -> Submission {
+		Submission {
+			day: 1,
+			part: Part::One,
+			answer: Answer::from(answer),
+			outcome,
+			wait: None,
+			at: 1000,
+		}
+	}
+
+	fn waited(answer: i32, outcome: Outcome, wait: u64) -> Submission {
+		Submission {
+			wait: Some(Duration::from_secs(wait)),
+			..submission(answer, outcome)
+		}
+	}
+
+	#[test]
+	fn test_parse_outcome() {
+		assert_eq!(
+			Outcome::parse("<p>That's the right answer!  You are one gold star closer.</p>"),
+			Outcome::Correct
+		);
+		assert_eq!(
+			Response::parse(
+				"<p>That's not the right answer; your answer is too high.  \
+				Please wait one minute before trying again.</p>"
+			),
+			Response {
+				outcome: Outcome::TooHigh,
+				wait: Some(Duration::from_secs(60)),
+			}
+		);
+		assert_eq!(
+			Response::parse(
+				"<p>That's not the right answer.  Because you have guessed incorrectly 4 times \
+				on this puzzle, please wait 5 minutes before trying again.</p>"
+			),
+			Response {
+				outcome: Outcome::Incorrect,
+				wait: Some(Duration::from_secs(300)),
+			}
+		);
+		assert_eq!(
+			Outcome::parse("<p>That's not the right answer; your answer is too low.</p>"),
+			Outcome::TooLow
+		);
+		assert_eq!(
+			Response::parse(
+				"<p>You gave an answer too recently; you have to wait after submitting an \
+				answer before trying again.  You have 4m 32s left to wait.</p>"
+			),
+			Response {
+				outcome: Outcome::Wait,
+				wait: Some(Duration::from_secs(272)),
+			}
+		);
+		assert_eq!(
+			Outcome::parse("<p>You don't seem to be solving the right level.</p>"),
+			Outcome::AlreadySolved
+		);
+	}
+
+	#[test]
+	fn test_check_refuses_known_wrong_answers() {
+		let history = History {
+			path: PathBuf::new(),
+			submissions: vec![
+				submission(500, Outcome::TooHigh),
+				submission(100, Outcome::TooLow),
+				submission(300, Outcome::Incorrect),
+			],
+		};
+		assert!(history.check(1, Part::One, &Answer::from(200), 2000).is_ok());
+		assert!(history.check(1, Part::Two, &Answer::from(300), 2000).is_ok());
+		for wrong in [600, 500, 100, 50, 300] {
+			assert!(matches!(
+				history.check(1, Part::One, &Answer::from(wrong), 2000),
+				Err(Error::Submission(_))
+			));
+		}
+	}
+
+	#[test]
+	fn test_wait_remaining() {
+		let history = History {
+			path: PathBuf::new(),
+			submissions: vec![waited(1, Outcome::Wait, 60)],
+		};
+		assert_eq!(history.wait_remaining(1030), Some(Duration::from_secs(30)));
+		assert_eq!(history.wait_remaining(1060), None);
+		assert!(history.check(1, Part::One, &Answer::from(2), 1030).is_err());
+	}
+
+	#[test]
+	fn test_wrong_answer_cooldown_blocks_resubmit() {
+		let history = History {
+			path: PathBuf::new(),
+			submissions: vec![waited(500, Outcome::TooHigh, 60)],
+		};
+		assert!(matches!(
+			history.check(1, Part::One, &Answer::from(400), 1000),
+			Err(Error::Submission(_))
+		));
+		assert!(history.check(1, Part::One, &Answer::from(400), 1060).is_ok());
+	}
+
+	#[test]
+	fn test_record_and_load() {
+		let dir = temp_dir("history");
+		let path = dir.join("submissions.toml");
+		let mut history = History::load(&path).unwrap();
+		let entries = [
+			submission(500, Outcome::TooHigh),
+			waited(450, Outcome::TooHigh, 60),
+			waited(42, Outcome::Wait, 272),
+		];
+		for entry in entries.clone() {
+			history.record(entry).unwrap();
+		}
+		assert_eq!(History::load(&path).unwrap().submissions(), &entries);
+
+		let mut text = fs::read_to_string(&path).unwrap();
+		text.push_str("[[submission]]\nday = 1\n");
+		fs::write(&path, text).unwrap();
+		match History::load(&path) {
+			Err(error) => assert_eq!(
+				error.to_string(),
+				format!("Parse error in {} on line 24: invalid submission #4", path.display())
+			),
+			_ => panic!("expected a parse error"),
+		}
+		fs::remove_dir_all(&dir).unwrap();
+	}
+}
@@ -0,0 +1,50 @@
+use crate::{Error, Result};
+use serde::de::DeserializeOwned;
+use std::path::Path;
+use toml::Spanned;
+
+pub fn line_of(text: &str, offset: usize) -> usize {
+	text[..offset.min(text.len())].matches('\n').count() + 1
+}
+
+fn error_in(path: &Path, line: Option<usize>, message: impl Into<String>) -> Error {
+	Error::Parse {
+		input: Some(path.display().to_string()),
+		line,
+		message: message.into(),
+	}
+}
+
+// Values deserialized as `Spanned` remember where they came from, so checks
+// made after parsing can still point at the right line of the file.
+pub fn parse<T: DeserializeOwned>(path: &Path, text: &str) -> Result<T> {
+	toml::from_str(text).map_err(|error| {
+		let line = error.span().map(|span| line_of(text, span.start));
+		error_in(path, line, error.message())
+	})
+}
+
+pub fn invalid<T>(path: &Path, text: &str, value: &Spanned<T>, message: impl Into<String>) -> Error {
+	error_in(path, Some(line_of(text, value.span().start)), message)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use std::collections::BTreeMap;
+	use toml::Value;
+
+	#[test]
+	fn test_errors_name_the_file_and_line() {
+		let path = Path::new("inputs/example.toml");
+		let error = parse::<BTreeMap<String, Value>>(path, "a = 1\nb = \n").unwrap_err();
+		assert!(matches!(error, Error::Parse { line: Some(2), .. }));
+
+		let text = "a = 1\n\nb = [2]\n";
+		let table = parse::<BTreeMap<String, Spanned<Value>>>(path, text).unwrap();
+		assert_eq!(
+			invalid(path, text, &table["b"], "b must be a string").to_string(),
+			"Parse error in inputs/example.toml on line 3: b must be a string"
+		);
+	}
+}
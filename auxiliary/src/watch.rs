@@ -0,0 +1,141 @@
+use crate::cli_opts::{Format, Options};
+use crate::io_::Input;
+use crate::output::{self, PartResult};
+use crate::runner::Day;
+use crate::{Answer, Error, Result};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+pub const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+struct Watched {
+	input: Input,
+	path: PathBuf,
+	modified: Option<SystemTime>,
+	results: Vec<PartResult>,
+}
+
+fn modified(path: &Path) -> Option<SystemTime> {
+	fs::metadata(path)
+		.and_then(|metadata| metadata.modified())
+		.ok()
+}
+
+// Answers are compared line by line so a single changed row of an image
+// stands out instead of the whole block.
+pub fn diff(previous: &Answer, current: &Answer) -> Option<String> {
+	if previous == current {
+		return None;
+	}
+	let previous = previous.to_string();
+	let current = current.to_string();
+	let previous = previous.lines().collect::<Vec<&str>>();
+	let current = current.lines().collect::<Vec<&str>>();
+
+	let mut lines = Vec::new();
+	for idx in 0..previous.len().max(current.len()) {
+		match (previous.get(idx), current.get(idx)) {
+			(Some(old), Some(new)) if old == new => lines.push(format!("  {}", new)),
+			(old, new) => {
+				lines.extend(old.map(|old| format!("- {}", old)));
+				lines.extend(new.map(|new| format!("+ {}", new)));
+			}
+		}
+	}
+	Some(lines.join("\n"))
+}
+
+fn print_change(result: &PartResult, previous: Option<&PartResult>, options: &Options) {
+	let previous = match previous {
+		Some(previous) if options.format == Format::Text && !options.quiet => previous,
+		_ => return result.print(options),
+	};
+	let elapsed = match options.time {
+		true => format!(" ({:?})", result.elapsed),
+		false => String::new(),
+	};
+	match diff(&previous.answer, &result.answer) {
+		None => output::print_labelled(
+			&format!("{}{} unchanged", output::label(result.part), elapsed),
+			&result.answer.to_string(),
+		),
+		Some(diff) => println!(
+			"{}{} changed:\n{}",
+			output::label(result.part),
+			elapsed,
+			diff
+		),
+	}
+}
+
+fn rerun(day: Day, watched: &Watched, options: &Options) -> Result<Vec<PartResult>> {
+	let results = (day.solve)(&watched.input.read_with(options.encoding)?, options.part)
+		.map_err(|error| error.with_input(&watched.input.to_string()))?;
+	for result in &results {
+		let previous = watched
+			.results
+			.iter()
+			.find(|previous| previous.part == result.part);
+		print_change(result, previous, options);
+	}
+	Ok(results)
+}
+
+// Solutions are compiled in, so only the inputs can be watched; errors while
+// a file is half-written are reported and the previous answers are kept.
+pub fn watch(day: Day, options: &Options) -> Result<()> {
+	let mut watched = options
+		.inputs
+		.iter()
+		.map(|input| match input {
+			Input::File(path) => Ok(Watched {
+				input: input.clone(),
+				path: path.clone(),
+				modified: None,
+				results: Vec::new(),
+			}),
+			_ => Err(Error::MissingArgument("input file to watch with --watch")),
+		})
+		.collect::<Result<Vec<Watched>>>()?;
+
+	loop {
+		for entry in &mut watched {
+			let modified = modified(&entry.path);
+			if modified.is_none() || modified == entry.modified {
+				continue;
+			}
+			if options.format == Format::Text && !options.quiet {
+				match entry.modified {
+					None => println!("Watching {}", entry.input),
+					Some(_) => println!("{} changed", entry.input),
+				}
+			}
+			entry.modified = modified;
+			match rerun(day, entry, options) {
+				Ok(results) => entry.results = results,
+				Err(error) => eprintln!("{}", error),
+			}
+		}
+		thread::sleep(POLL_INTERVAL);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::grid::Grid;
+
+	#[test]
+	fn test_diff() {
+		assert_eq!(diff(&Answer::from(7), &Answer::from(7)), None);
+		assert_eq!(
+			diff(&Answer::from(24000), &Answer::from(24001)).unwrap(),
+			"- 24000\n+ 24001"
+		);
+		let previous = Answer::from(Grid::from_cells(2, vec![true, false, false, true]));
+		let current = Answer::from(Grid::from_cells(2, vec![true, false, true, true]));
+		assert_eq!(diff(&previous, &current).unwrap(), "  #.\n- .#\n+ ##");
+	}
+}
@@ -0,0 +1,72 @@
+use auxiliary::io_;
+use auxiliary::solution::Solution;
+use auxiliary::Error;
+
+fn calorie_sum(first_line: usize, calorie_group: &[&str]) -> auxiliary::Result<i32> {
+	calorie_group
+		.iter()
+		.zip(first_line..)
+		.try_fold(0, |sum, (raw_count, line)| {
+			let calories = raw_count
+				.parse::<i32>()
+				.map_err(|_| Error::parse(line, format!("invalid calorie count {:?}", raw_count)))?;
+			Ok(sum + calories)
+		})
+}
+
+pub fn parse(input: &str) -> auxiliary::Result<Vec<i32>> {
+	let mut sums = io_::split_groups(input.lines())
+		.map(|(first_line, calorie_group)| calorie_sum(first_line, &calorie_group))
+		.collect::<auxiliary::Result<Vec<i32>>>()?;
+	sums.sort();
+	Ok(sums)
+}
+
+pub fn part_one(calorie_sums: &[i32]) -> i32 {
+	*calorie_sums.last().unwrap()
+}
+
+pub fn part_two(calorie_sums: &[i32]) -> i32 {
+	calorie_sums.iter().rev().take(3).sum()
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+	const DAY: u8 = 1;
+
+	type Model = Vec<i32>;
+	type PartOne = i32;
+	type PartTwo = i32;
+
+	fn parse(input: &str) -> auxiliary::Result<Self::Model> {
+		parse(input)
+	}
+
+	fn part_one(model: &Self::Model) -> Self::PartOne {
+		part_one(model)
+	}
+
+	fn part_two(model: &Self::Model) -> Self::PartTwo {
+		part_two(model)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	const EXAMPLE: &str = include_str!("../example.txt");
+
+	#[test]
+	fn test_part_one_example() {
+		let model = Day01::parse(EXAMPLE).unwrap();
+		assert_eq!(Day01::part_one(&model), 24000);
+	}
+
+	#[test]
+	fn test_part_two_example() {
+		let model = Day01::parse(EXAMPLE).unwrap();
+		assert_eq!(Day01::part_two(&model), 45000);
+	}
+}
@@ -0,0 +1,177 @@
+use auxiliary::solution::Solution;
+use auxiliary::Error;
+use std::borrow::Borrow;
+use std::iter::Iterator;
+use std::str::FromStr;
+
+#[derive(Eq, PartialEq, PartialOrd, Clone, Copy)]
+pub enum GameMoves {
+	Rock = 1,
+	Paper = 2,
+	Scissors = 3,
+}
+
+enum GameResult {
+	Win = 6,
+	Lose = 0,
+	Draw = 3,
+}
+
+impl FromStr for GameMoves {
+	type Err = ();
+
+	fn from_str(raw: &str) -> Result<Self, Self::Err> {
+		match raw {
+			"A" => Ok(GameMoves::Rock),
+			"B" => Ok(GameMoves::Paper),
+			"C" => Ok(GameMoves::Scissors),
+			_ => Err(()),
+		}
+	}
+}
+
+impl GameMoves {
+	fn from_suggestion(suggestion: Suggestion) -> Self {
+		match suggestion {
+			Suggestion::X => GameMoves::Rock,
+			Suggestion::Y => GameMoves::Paper,
+			Suggestion::Z => GameMoves::Scissors,
+		}
+	}
+}
+
+impl GameResult {
+	fn from_suggestion(suggestion: Suggestion) -> Self {
+		match suggestion {
+			Suggestion::X => GameResult::Lose,
+			Suggestion::Y => GameResult::Draw,
+			Suggestion::Z => GameResult::Win,
+		}
+	}
+}
+
+fn beater(a: &GameMoves) -> GameMoves {
+	match a {
+		GameMoves::Rock => GameMoves::Paper,
+		GameMoves::Paper => GameMoves::Scissors,
+		GameMoves::Scissors => GameMoves::Rock,
+	}
+}
+
+fn loser(a: &GameMoves) -> GameMoves {
+	match a {
+		GameMoves::Rock => GameMoves::Scissors,
+		GameMoves::Paper => GameMoves::Rock,
+		GameMoves::Scissors => GameMoves::Paper,
+	}
+}
+
+fn confront(a: &GameMoves, b: &GameMoves) -> GameResult {
+	if a == b { GameResult::Draw }
+	else if a == loser(b).borrow() { GameResult::Lose }
+	else if a == beater(b).borrow() { GameResult::Win }
+	else { GameResult::Lose }
+}
+
+#[derive(Clone, Copy)]
+pub enum Suggestion {
+	X,
+	Y,
+	Z,
+}
+
+impl FromStr for Suggestion {
+	type Err = ();
+
+	fn from_str(raw: &str) -> Result<Self, Self::Err> {
+		match raw {
+			"X" => Ok(Suggestion::X),
+			"Y" => Ok(Suggestion::Y),
+			"Z" => Ok(Suggestion::Z),
+			_ => Err(()),
+		}
+	}
+}
+
+fn parse_game_move_suggestion_pair(raw_pair: &str) -> Option<(GameMoves, Suggestion)> {
+	let (raw_move, raw_suggestion) = raw_pair.split_once(' ')?;
+	Some((raw_move.parse().ok()?, raw_suggestion.parse().ok()?))
+}
+
+pub fn parse(input: &str) -> auxiliary::Result<Vec<(GameMoves, Suggestion)>> {
+	input
+		.lines()
+		.enumerate()
+		.map(|(idx, line)| {
+			parse_game_move_suggestion_pair(line)
+				.ok_or_else(|| Error::parse(idx + 1, format!("invalid strategy line {:?}", line)))
+		})
+		.collect()
+}
+
+pub fn part_one(strategy: &[(GameMoves, Suggestion)]) -> i32 {
+	strategy
+		.iter()
+		.fold(0, |accumulated, &(opponent_move, suggestion)| {
+			let suggested_move = GameMoves::from_suggestion(suggestion);
+			accumulated
+				+ (confront(&suggested_move, &opponent_move) as i32)
+				+ (suggested_move as i32)
+		})
+}
+
+pub fn part_two(strategy: &[(GameMoves, Suggestion)]) -> i32 {
+	strategy
+		.iter()
+		.fold(0, |accumulated, &(opponent_move, suggestion)| {
+			let suggested_move = match GameResult::from_suggestion(suggestion) {
+				GameResult::Win => beater(opponent_move.borrow()),
+				GameResult::Lose => loser(opponent_move.borrow()),
+				GameResult::Draw => opponent_move,
+			};
+			accumulated
+				+ (confront(&suggested_move, &opponent_move) as i32)
+				+ (suggested_move as i32)
+		})
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+	const DAY: u8 = 2;
+
+	type Model = Vec<(GameMoves, Suggestion)>;
+	type PartOne = i32;
+	type PartTwo = i32;
+
+	fn parse(input: &str) -> auxiliary::Result<Self::Model> {
+		parse(input)
+	}
+
+	fn part_one(model: &Self::Model) -> Self::PartOne {
+		part_one(model)
+	}
+
+	fn part_two(model: &Self::Model) -> Self::PartTwo {
+		part_two(model)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	const EXAMPLE: &str = include_str!("../example.txt");
+
+	#[test]
+	fn test_part_one_example() {
+		let model = Day02::parse(EXAMPLE).unwrap();
+		assert_eq!(Day02::part_one(&model), 15);
+	}
+
+	#[test]
+	fn test_part_two_example() {
+		let model = Day02::parse(EXAMPLE).unwrap();
+		assert_eq!(Day02::part_two(&model), 12);
+	}
+}
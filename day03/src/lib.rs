@@ -0,0 +1,90 @@
+use auxiliary::bitset::{letter_index, Letters};
+use auxiliary::solution::Solution;
+use auxiliary::Error;
+
+const fn div_ceil(lhs: i32, rhs: i32) -> i32 {
+	let d = lhs / rhs;
+	let r = lhs % rhs;
+	if (r > 0 && rhs > 0) || (r < 0 && rhs < 0) {
+		d + 1
+	} else {
+		d
+	}
+}
+
+fn items(rucksack: &str) -> Letters {
+	rucksack.chars().filter_map(letter_index).collect()
+}
+
+fn priority(shared_items: Letters) -> Option<i32> {
+	shared_items.iter().next().map(|item| item as i32 + 1)
+}
+
+pub fn parse(input: &str) -> auxiliary::Result<Vec<String>> {
+	input
+		.lines()
+		.enumerate()
+		.map(|(idx, line)| match line.chars().all(|item| item.is_ascii_alphabetic()) {
+			true => Ok(line.to_string()),
+			false => Err(Error::parse(idx + 1, format!("invalid rucksack {:?}", line))),
+		})
+		.collect()
+}
+
+pub fn part_one(rucksacks: &[String]) -> i32 {
+	rucksacks
+		.iter()
+		.filter_map(|line| {
+			let (left, right) = line.split_at(div_ceil(line.len() as i32, 2) as usize);
+			priority(items(left) & items(right))
+		})
+		.sum()
+}
+
+pub fn part_two(rucksacks: &[String]) -> i32 {
+	rucksacks
+		.chunks_exact(3)
+		.filter_map(|chunk| priority(items(&chunk[0]) & items(&chunk[1]) & items(&chunk[2])))
+		.sum()
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+	const DAY: u8 = 3;
+
+	type Model = Vec<String>;
+	type PartOne = i32;
+	type PartTwo = i32;
+
+	fn parse(input: &str) -> auxiliary::Result<Self::Model> {
+		parse(input)
+	}
+
+	fn part_one(model: &Self::Model) -> Self::PartOne {
+		part_one(model)
+	}
+
+	fn part_two(model: &Self::Model) -> Self::PartTwo {
+		part_two(model)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	const EXAMPLE: &str = include_str!("../example.txt");
+
+	#[test]
+	fn test_part_one_example() {
+		let model = Day03::parse(EXAMPLE).unwrap();
+		assert_eq!(Day03::part_one(&model), 157);
+	}
+
+	#[test]
+	fn test_part_two_example() {
+		let model = Day03::parse(EXAMPLE).unwrap();
+		assert_eq!(Day03::part_two(&model), 70);
+	}
+}
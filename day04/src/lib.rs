@@ -0,0 +1,84 @@
+use auxiliary::interval::Interval;
+use auxiliary::solution::Solution;
+use auxiliary::Error;
+
+pub type CleaningRange = Interval<u32>;
+
+fn parse_cleaning_range(raw_cleaning_range: &str) -> Option<CleaningRange> {
+	let (raw_lower, raw_upper) = raw_cleaning_range.split_once('-')?;
+	Some(Interval::inclusive(raw_lower.parse().ok()?, raw_upper.parse().ok()?))
+}
+
+fn parse_cleaning_range_pair(raw_pair: &str) -> Option<(CleaningRange, CleaningRange)> {
+	let (raw_left, raw_right) = raw_pair.split_once(',')?;
+	Some((
+		parse_cleaning_range(raw_left)?,
+		parse_cleaning_range(raw_right)?,
+	))
+}
+
+pub fn parse(input: &str) -> auxiliary::Result<Vec<(CleaningRange, CleaningRange)>> {
+	input
+		.lines()
+		.enumerate()
+		.map(|(idx, line)| {
+			parse_cleaning_range_pair(line)
+				.ok_or_else(|| Error::parse(idx + 1, format!("invalid range pair {:?}", line)))
+		})
+		.collect()
+}
+
+pub fn part_one(range_pairs: &[(CleaningRange, CleaningRange)]) -> u32 {
+	range_pairs
+		.iter()
+		.filter(|(left, right)| left.contains_interval(*right) || right.contains_interval(*left))
+		.count() as u32
+}
+
+pub fn part_two(range_pairs: &[(CleaningRange, CleaningRange)]) -> u32 {
+	range_pairs
+		.iter()
+		.filter(|(left, right)| left.overlaps(*right))
+		.count() as u32
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+	const DAY: u8 = 4;
+
+	type Model = Vec<(CleaningRange, CleaningRange)>;
+	type PartOne = u32;
+	type PartTwo = u32;
+
+	fn parse(input: &str) -> auxiliary::Result<Self::Model> {
+		parse(input)
+	}
+
+	fn part_one(model: &Self::Model) -> Self::PartOne {
+		part_one(model)
+	}
+
+	fn part_two(model: &Self::Model) -> Self::PartTwo {
+		part_two(model)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	const EXAMPLE: &str = include_str!("../example.txt");
+
+	#[test]
+	fn test_part_one_example() {
+		let model = Day04::parse(EXAMPLE).unwrap();
+		assert_eq!(Day04::part_one(&model), 2);
+	}
+
+	#[test]
+	fn test_part_two_example() {
+		let model = Day04::parse(EXAMPLE).unwrap();
+		assert_eq!(Day04::part_two(&model), 4);
+	}
+}
@@ -0,0 +1,292 @@
+use auxiliary::diagnostics::{Diagnostics, Fault};
+use auxiliary::io_;
+use auxiliary::parse::{integer, preceded, run, PResult, ParseError};
+use auxiliary::solution::Solution;
+use auxiliary::Error;
+use std::collections::VecDeque;
+
+struct MoveDirection {
+	source_ordinal: usize,
+	target_ordinal: usize,
+}
+
+struct Move {
+	direction: MoveDirection,
+	no_of_crates: usize,
+}
+
+fn parse_move(input: &str) -> PResult<'_, Move> {
+	let (no_of_crates, input) = preceded("move ", integer)(input)?;
+	let (source_ordinal, input) = preceded(" from ", integer)(input)?;
+	let (target_ordinal, input) = preceded(" to ", integer)(input)?;
+	Ok((
+		Move {
+			direction: MoveDirection {
+				source_ordinal,
+				target_ordinal,
+			},
+			no_of_crates,
+		},
+		input,
+	))
+}
+
+impl Move {
+	fn from_str(raw_move: &str) -> Result<Self, ParseError> {
+		run(parse_move, raw_move)
+	}
+}
+
+type Stack = VecDeque<char>;
+type Stacks = Vec<Stack>;
+
+pub struct Procedure {
+	stacks: Stacks,
+	moves: Vec<Move>,
+}
+
+// The numbered line under the stacks fixes the column each stack's crates sit
+// in, so crates are only accepted in those columns.
+fn parse_initial_layout(
+	raw_layout: &[&str],
+	first_line: usize,
+	diagnostics: &mut Diagnostics,
+) -> Stacks {
+	let Some((header, rows)) = raw_layout.split_last() else {
+		return Stacks::new();
+	};
+	let header_line = first_line + rows.len();
+	let mut columns = Vec::new();
+	let mut offset = 0;
+	for token in header.split(' ') {
+		if !token.is_empty() {
+			match token.parse::<usize>() {
+				Ok(ordinal) if ordinal == columns.len() + 1 => columns.push(offset),
+				_ => {
+					let message = format!("expected stack number {}", columns.len() + 1);
+					diagnostics.report(header_line, header, Fault::new(token, message));
+				}
+			}
+		}
+		offset += token.len() + 1;
+	}
+	if columns.is_empty() && diagnostics.is_empty() {
+		diagnostics.report(header_line, header, Fault::after(header, "missing stack numbers"));
+	}
+
+	let mut crate_stacks: Stacks = vec![Stack::new(); columns.len()];
+	for (idx, raw_stack_row) in rows.iter().enumerate().rev() {
+		for (column, _crate) in raw_stack_row.char_indices() {
+			let token = &raw_stack_row[column..column + _crate.len_utf8()];
+			let fault = match _crate {
+				'[' | ']' | ' ' => continue,
+				_ if !_crate.is_ascii_uppercase() => Fault::new(token, "expected a crate letter"),
+				_ => match columns.iter().position(|&stack_column| stack_column == column) {
+					Some(stack_idx) => {
+						crate_stacks[stack_idx].push_back(_crate);
+						continue;
+					}
+					None => Fault::new(token, "crate is not above a stack number"),
+				},
+			};
+			diagnostics.report(first_line + idx, raw_stack_row, fault);
+		}
+	}
+	crate_stacks
+}
+
+// Both parts move the same number of crates between the same stacks, so
+// tracking the heights is enough to reject moves that cannot be carried out.
+// Once a move is rejected the heights are unknown and only the stack numbers
+// are still checked.
+fn check_move<'a>(
+	stack_count: usize,
+	heights: &mut Option<Vec<usize>>,
+	_move: &Move,
+	raw_move: &'a str,
+) -> Result<(), Fault<'a>> {
+	// A parsed move reads "move N from S to T", so its numbers are words 1, 3 and 5.
+	let word = |idx: usize| raw_move.split(' ').nth(idx).unwrap_or(raw_move);
+	let stack_idx = |ordinal: usize, idx: usize| match (1..=stack_count).contains(&ordinal) {
+		true => Ok(ordinal - 1),
+		false => Err(Fault::new(word(idx), format!("there is no stack {}", ordinal))),
+	};
+	let source = stack_idx(_move.direction.source_ordinal, 3)?;
+	let target = stack_idx(_move.direction.target_ordinal, 5)?;
+	let Some(known_heights) = heights else {
+		return Ok(());
+	};
+	if known_heights[source] < _move.no_of_crates {
+		let message = format!("stack {} holds only {} crates", source + 1, known_heights[source]);
+		return Err(Fault::new(word(1), message));
+	}
+	known_heights[source] -= _move.no_of_crates;
+	known_heights[target] += _move.no_of_crates;
+	Ok(())
+}
+
+fn apply_move(stacks: &mut Stacks, direction: &MoveDirection, no_of_crates: usize) {
+	let slice_idx = stacks[direction.source_ordinal - 1].len() - no_of_crates;
+	let mut sliced_off_substack = stacks[direction.source_ordinal - 1].split_off(slice_idx);
+	stacks[direction.target_ordinal - 1].append(&mut sliced_off_substack);
+}
+
+fn top_crates(stacks: &Stacks) -> String {
+	stacks.iter().filter_map(|stack| stack.back()).collect()
+}
+
+pub fn parse(input: &str) -> auxiliary::Result<Procedure> {
+	let mut groups = io_::split_groups(input.lines());
+	let (first_line, layout) = groups
+		.next()
+		.ok_or_else(|| Error::parse(1, "missing stack layout"))?;
+	let mut diagnostics = Diagnostics::new();
+	let stacks = parse_initial_layout(&layout, first_line, &mut diagnostics);
+	let layout_is_valid = diagnostics.is_empty();
+	let mut heights = Some(stacks.iter().map(Stack::len).collect::<Vec<usize>>());
+	let mut moves = Vec::new();
+	let mut separated = false;
+	for (first_line, raw_moves) in groups {
+		separated = true;
+		for (line, raw_move) in (first_line..).zip(raw_moves) {
+			match Move::from_str(raw_move) {
+				Ok(_move) if !layout_is_valid => moves.push(_move),
+				Ok(_move) => match check_move(stacks.len(), &mut heights, &_move, raw_move) {
+					Ok(()) => moves.push(_move),
+					Err(fault) => {
+						heights = None;
+						diagnostics.report(line, raw_move, fault);
+					}
+				},
+				Err(error) => {
+					heights = None;
+					diagnostics.report(line, raw_move, error.fault(raw_move));
+				}
+			}
+		}
+	}
+	if !separated {
+		let last_line = first_line + layout.len() - 1;
+		return Err(Error::parse(last_line, "missing blank line after the stack layout"));
+	}
+
+	diagnostics.finish(Procedure { stacks, moves })
+}
+
+pub fn part_one(procedure: &Procedure) -> String {
+	let mut stacks = procedure.stacks.clone();
+
+	procedure.moves.iter().for_each(|_move| {
+		for _ in 0.._move.no_of_crates {
+			apply_move(&mut stacks, &_move.direction, 1)
+		}
+	});
+
+	top_crates(&stacks)
+}
+
+pub fn part_two(procedure: &Procedure) -> String {
+	let mut stacks = procedure.stacks.clone();
+
+	procedure
+		.moves
+		.iter()
+		.for_each(|_move| apply_move(&mut stacks, &_move.direction, _move.no_of_crates));
+
+	top_crates(&stacks)
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+	const DAY: u8 = 5;
+
+	type Model = Procedure;
+	type PartOne = String;
+	type PartTwo = String;
+
+	fn parse(input: &str) -> auxiliary::Result<Self::Model> {
+		parse(input)
+	}
+
+	fn part_one(model: &Self::Model) -> Self::PartOne {
+		part_one(model)
+	}
+
+	fn part_two(model: &Self::Model) -> Self::PartTwo {
+		part_two(model)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	const EXAMPLE: &str = include_str!("../example.txt");
+
+	#[test]
+	fn test_part_one_example() {
+		let model = Day05::parse(EXAMPLE).unwrap();
+		assert_eq!(Day05::part_one(&model), "CMZ");
+	}
+
+	#[test]
+	fn test_part_two_example() {
+		let model = Day05::parse(EXAMPLE).unwrap();
+		assert_eq!(Day05::part_two(&model), "MCD");
+	}
+
+	#[test]
+	fn test_parse_reports_every_bad_move() {
+		let input = EXAMPLE.replace("move 2 from 2 to 1", "move two from 2 to 1") + "move 1 from 2\n";
+		match parse(&input) {
+			Err(Error::Diagnostics(diagnostics)) => assert_eq!(
+				diagnostics
+					.iter()
+					.map(|diagnostic| (diagnostic.line, diagnostic.column))
+					.collect::<Vec<_>>(),
+				vec![(8, 6), (10, 14)]
+			),
+			_ => panic!("expected diagnostics"),
+		}
+	}
+
+	fn diagnostics_of(input: &str) -> Vec<(usize, usize, String)> {
+		match parse(input) {
+			Err(Error::Diagnostics(diagnostics)) => diagnostics
+				.iter()
+				.map(|diagnostic| (diagnostic.line, diagnostic.column, diagnostic.message.clone()))
+				.collect(),
+			_ => panic!("expected diagnostics"),
+		}
+	}
+
+	#[test]
+	fn test_parse_reports_bad_layouts() {
+		assert_eq!(
+			diagnostics_of("[A]\n\nmove 1 from 1 to 1"),
+			vec![(1, 1, "expected stack number 1".to_string())]
+		);
+		assert_eq!(
+			diagnostics_of("[A] [b]\n 1   2\n\nmove 1 from 1 to 2"),
+			vec![(1, 6, "expected a crate letter".to_string())]
+		);
+		assert_eq!(
+			diagnostics_of("  [A]\n 1   2\n\nmove 1 from 1 to 2"),
+			vec![(1, 4, "crate is not above a stack number".to_string())]
+		);
+	}
+
+	#[test]
+	fn test_parse_checks_moves_against_the_stacks() {
+		assert_eq!(
+			diagnostics_of("[A]\n 1\n\nmove 1 from 3 to 1\nmove 1 from 1 to 2"),
+			vec![
+				(4, 13, "there is no stack 3".to_string()),
+				(5, 18, "there is no stack 2".to_string())
+			]
+		);
+		let input = EXAMPLE.replace("move 3 from 1 to 3", "move 4 from 1 to 3");
+		assert_eq!(diagnostics_of(&input), vec![(7, 6, "stack 1 holds only 3 crates".to_string())]);
+	}
+}
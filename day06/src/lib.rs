@@ -0,0 +1,90 @@
+use auxiliary::bitset::{letter_index, Letters};
+use auxiliary::solution::Solution;
+use auxiliary::Error;
+
+fn position_of_n_uniq(string: &str, n: usize) -> auxiliary::Result<usize> {
+	string
+		.as_bytes()
+		.windows(n)
+		.position(|window| {
+			let unique_chars: Letters = window
+				.iter()
+				.filter_map(|&byte| letter_index(byte as char))
+				.collect();
+			unique_chars.len() == n
+		})
+		.map(|position| position + n)
+		.ok_or_else(|| Error::NoAnswer(format!("no {} consecutive characters are all different", n)))
+}
+
+pub fn parse(input: &str) -> auxiliary::Result<String> {
+	let datastream = input
+		.lines()
+		.next()
+		.ok_or_else(|| Error::parse(1, "missing datastream buffer"))?;
+	match datastream.chars().all(|signal| letter_index(signal).is_some()) {
+		true => Ok(datastream.to_string()),
+		false => Err(Error::parse(1, format!("invalid datastream {:?}", datastream))),
+	}
+}
+
+pub fn part_one(datastream: &str) -> auxiliary::Result<usize> {
+	position_of_n_uniq(datastream, 4)
+}
+
+pub fn part_two(datastream: &str) -> auxiliary::Result<usize> {
+	position_of_n_uniq(datastream, 14)
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+	const DAY: u8 = 6;
+
+	type Model = String;
+	type PartOne = auxiliary::Result<usize>;
+	type PartTwo = auxiliary::Result<usize>;
+
+	fn parse(input: &str) -> auxiliary::Result<Self::Model> {
+		parse(input)
+	}
+
+	fn part_one(model: &Self::Model) -> Self::PartOne {
+		part_one(model)
+	}
+
+	fn part_two(model: &Self::Model) -> Self::PartTwo {
+		part_two(model)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	const EXAMPLE: &str = include_str!("../example.txt");
+
+	#[test]
+	fn test_part_one_example() {
+		let model = Day06::parse(EXAMPLE).unwrap();
+		assert_eq!(Day06::part_one(&model).unwrap(), 7);
+	}
+
+	#[test]
+	fn test_part_two_example() {
+		let model = Day06::parse(EXAMPLE).unwrap();
+		assert_eq!(Day06::part_two(&model).unwrap(), 19);
+	}
+
+	#[test]
+	fn test_no_marker() {
+		let model = Day06::parse("abcabcabcabcabcabc").unwrap();
+		assert_eq!(
+			Day06::part_one(&model).unwrap_err().to_string(),
+			"No answer: no 4 consecutive characters are all different."
+		);
+		let model = Day06::parse("abcd").unwrap();
+		assert_eq!(Day06::part_one(&model).unwrap(), 4);
+		assert!(matches!(Day06::part_two(&model), Err(Error::NoAnswer(_))));
+	}
+}
@@ -0,0 +1,184 @@
+use auxiliary::arena::{Arena, Id};
+use auxiliary::solution::Solution;
+use auxiliary::Error;
+use std::collections::HashMap;
+
+pub struct FileSystem {
+	directories: Arena<Directory>,
+	root: Id<Directory>,
+}
+
+impl FileSystem {
+	pub fn root(&self) -> Id<Directory> {
+		self.root
+	}
+
+	pub fn directories(&self) -> &Arena<Directory> {
+		&self.directories
+	}
+
+	pub fn sizes(&self) -> HashMap<Id<Directory>, u64> {
+		self.directories.fold(self.root, total_size)
+	}
+
+	pub fn get_size(&self, directory: Id<Directory>) -> u64 {
+		self.directories.fold(directory, total_size)[&directory]
+	}
+}
+
+#[derive(Default)]
+pub struct Directory {
+	pub files: Vec<u64>,
+}
+
+fn total_size(directory: &Directory, subdirectories: &[&u64]) -> u64 {
+	directory.files.iter().sum::<u64>() + subdirectories.iter().copied().sum::<u64>()
+}
+
+pub fn parse_file_structure(raw_console_output: &[String]) -> auxiliary::Result<FileSystem> {
+	let mut directories = Arena::new();
+	let root = directories.insert(Directory::default());
+	let mut current_directory = root;
+
+	for (idx, line) in raw_console_output.iter().enumerate() {
+		let invalid = |message: &str| Error::parse(idx + 1, format!("{} {:?}", message, line));
+		match line.split_whitespace().collect::<Vec<&str>>()[..] {
+			["$", "ls"] => {}
+			["$", "cd", "/"] => current_directory = root,
+			["$", "cd", ".."] => {
+				current_directory = directories
+					.parent(current_directory)
+					.ok_or_else(|| invalid("no parent directory for"))?
+			}
+			["$", "cd", name] => {
+				current_directory = directories
+					.child(current_directory, name)
+					.ok_or_else(|| invalid("unknown directory in"))?
+			}
+			["dir", name] => {
+				directories.add_named_child(current_directory, name, Directory::default());
+			}
+			[size, _] if !size.starts_with('$') => {
+				let size = size
+					.parse::<u64>()
+					.map_err(|_| invalid("invalid file size in"))?;
+				directories[current_directory].files.push(size);
+			}
+			_ => return Err(invalid("invalid terminal line")),
+		}
+	}
+
+	Ok(FileSystem { directories, root })
+}
+
+pub fn parse(input: &str) -> auxiliary::Result<FileSystem> {
+	parse_file_structure(&input.lines().map(String::from).collect::<Vec<String>>())
+}
+
+pub fn part_one(file_system: &FileSystem) -> u64 {
+	file_system
+		.sizes()
+		.into_values()
+		.filter(|size| size < &100000)
+		.sum::<u64>()
+}
+
+// Nothing has to go when there is already enough free space. Otherwise the
+// root itself always frees enough, even on an overfull disk.
+pub fn part_two(file_system: &FileSystem) -> u64 {
+	let space_total: u64 = 70000000;
+	let space_required: u64 = 30000000;
+	let sizes = file_system.sizes();
+	let space_occupied = sizes[&file_system.root()];
+	let space_missing = space_required.saturating_sub(space_total.saturating_sub(space_occupied));
+	if space_missing == 0 {
+		return 0;
+	}
+
+	sizes
+		.into_values()
+		.filter(|size| size >= &space_missing)
+		.min()
+		.unwrap_or(space_occupied)
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+	const DAY: u8 = 7;
+
+	type Model = FileSystem;
+	type PartOne = u64;
+	type PartTwo = u64;
+
+	fn parse(input: &str) -> auxiliary::Result<Self::Model> {
+		parse(input)
+	}
+
+	fn part_one(model: &Self::Model) -> Self::PartOne {
+		part_one(model)
+	}
+
+	fn part_two(model: &Self::Model) -> Self::PartTwo {
+		part_two(model)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	const EXAMPLE: &str = include_str!("../example.txt");
+
+	#[test]
+	fn test_parse_file_structure() {
+		let file_system = parse(EXAMPLE).unwrap();
+		let directories = file_system.directories();
+		assert_eq!(directories.len(), 4);
+		assert_eq!(file_system.get_size(file_system.root()), 48381165);
+		let a = directories.child(file_system.root(), "a").unwrap();
+		let e = directories.child(a, "e").unwrap();
+		assert_eq!(file_system.get_size(e), 584);
+	}
+
+	#[test]
+	fn test_parse_errors() {
+		let error = |input: &str| parse(input).err().unwrap().to_string();
+		assert_eq!(
+			error("$ cd /\n$ ls\nabc def"),
+			"Parse error on line 3: invalid file size in \"abc def\""
+		);
+		assert_eq!(
+			error("$ cd /\n$ cd x"),
+			"Parse error on line 2: unknown directory in \"$ cd x\""
+		);
+		assert_eq!(
+			error("$ cd ..\n$ ls"),
+			"Parse error on line 1: no parent directory for \"$ cd ..\""
+		);
+		assert_eq!(
+			error("$ rm -rf /"),
+			"Parse error on line 1: invalid terminal line \"$ rm -rf /\""
+		);
+	}
+
+	#[test]
+	fn test_part_one_example() {
+		let model = Day07::parse(EXAMPLE).unwrap();
+		assert_eq!(Day07::part_one(&model), 95437);
+	}
+
+	#[test]
+	fn test_part_two_example() {
+		let model = Day07::parse(EXAMPLE).unwrap();
+		assert_eq!(Day07::part_two(&model), 24933642);
+	}
+
+	#[test]
+	fn test_part_two_small_and_overfull_disks() {
+		let model = Day07::parse("$ cd /\n$ ls\ndir a\n100 b.txt\n$ cd a\n$ ls\n50 c.txt\n").unwrap();
+		assert_eq!(Day07::part_two(&model), 0);
+		let model = Day07::parse("$ cd /\n$ ls\ndir a\n80000000 huge.bin\n$ cd a\n$ ls\n5 c.txt\n").unwrap();
+		assert_eq!(Day07::part_two(&model), 80000005);
+	}
+}
@@ -0,0 +1,84 @@
+use auxiliary::direction::CARDINAL;
+use auxiliary::grid::Grid;
+use auxiliary::solution::Solution;
+use take_until::TakeUntilExt;
+
+pub type ForestGrid = Grid<i32>;
+
+pub fn parse(input: &str) -> auxiliary::Result<ForestGrid> {
+	Grid::parse(input.lines(), |raw_tree| {
+		raw_tree.to_digit(10).map(|tree| tree as i32)
+	})
+}
+
+pub fn part_one(forest_grid: &ForestGrid) -> i32 {
+	forest_grid
+		.iter()
+		.filter(|(point, tree_height)| {
+			CARDINAL.iter().any(|&direction| {
+				forest_grid
+					.ray(*point, direction)
+					.all(|(_, other_tree_height)| other_tree_height < tree_height)
+			})
+		})
+		.count() as i32
+}
+
+pub fn part_two(forest_grid: &ForestGrid) -> usize {
+	forest_grid
+		.iter()
+		.map(|(point, tree_height)| {
+			CARDINAL
+				.iter()
+				.map(|&direction| {
+					forest_grid
+						.ray(point, direction)
+						.take_until(|(_, other_tree_height)| other_tree_height >= &tree_height)
+						.count()
+				})
+				.product::<usize>()
+		})
+		.max()
+		.unwrap()
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+	const DAY: u8 = 8;
+
+	type Model = ForestGrid;
+	type PartOne = i32;
+	type PartTwo = usize;
+
+	fn parse(input: &str) -> auxiliary::Result<Self::Model> {
+		parse(input)
+	}
+
+	fn part_one(model: &Self::Model) -> Self::PartOne {
+		part_one(model)
+	}
+
+	fn part_two(model: &Self::Model) -> Self::PartTwo {
+		part_two(model)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	const EXAMPLE: &str = include_str!("../example.txt");
+
+	#[test]
+	fn test_part_one_example() {
+		let model = Day08::parse(EXAMPLE).unwrap();
+		assert_eq!(Day08::part_one(&model), 21);
+	}
+
+	#[test]
+	fn test_part_two_example() {
+		let model = Day08::parse(EXAMPLE).unwrap();
+		assert_eq!(Day08::part_two(&model), 8);
+	}
+}
@@ -0,0 +1,151 @@
+use auxiliary::direction::Direction;
+use auxiliary::point::Point2;
+use auxiliary::solution::Solution;
+use auxiliary::Error;
+use std::collections::HashSet;
+
+type Point = Point2<i32>;
+
+struct Knot {
+	position: Point,
+	visited_positions: HashSet<Point>,
+	follower: Option<Box<Knot>>,
+}
+
+impl Knot {
+	fn new(tail: Option<Box<Knot>>) -> Knot {
+		Knot {
+			follower: tail,
+			position: Point2::default(),
+			visited_positions: HashSet::from([Point2::default()]),
+		}
+	}
+
+	fn _move(&mut self, direction: Direction) {
+		let position = direction.apply(self.position).expect("rope left the plane");
+		self.change_position(position);
+	}
+
+	fn change_position(&mut self, point: Point) {
+		self.position = point;
+		self.visited_positions.insert(self.position);
+
+		if let Some(follower) = self.follower.as_mut() {
+			follower.follow(self.position);
+		}
+	}
+
+	// A knot that falls behind moves at most one square along each axis.
+	fn follow(&mut self, destination: Point) {
+		if self.position.chebyshev(destination) <= 1 {
+			return;
+		}
+		self.change_position(self.position + (destination - self.position).signum());
+	}
+}
+
+fn parse_steps(raw_steps: &str) -> Option<(Direction, usize)> {
+	let (raw_direction, raw_step_count) = raw_steps.split_once(' ')?;
+	Some((raw_direction.parse().ok()?, raw_step_count.parse().ok()?))
+}
+
+pub fn parse(input: &str) -> auxiliary::Result<Vec<(Direction, usize)>> {
+	input
+		.lines()
+		.enumerate()
+		.map(|(idx, line)| {
+			parse_steps(line)
+				.ok_or_else(|| Error::parse(idx + 1, format!("invalid motion {:?}", line)))
+		})
+		.collect()
+}
+
+fn steps(motions: &[(Direction, usize)]) -> impl Iterator<Item = Direction> + '_ {
+	motions
+		.iter()
+		.flat_map(|&(direction, count)| std::iter::repeat_n(direction, count))
+}
+
+pub fn part_one(motions: &[(Direction, usize)]) -> usize {
+	let mut head = Knot::new(None);
+	let tail = Knot::new(None);
+
+	head.follower = Some(Box::new(tail));
+
+	for step in steps(motions) {
+		head._move(step);
+	}
+
+	head.follower.unwrap().visited_positions.len()
+}
+
+pub fn part_two(motions: &[(Direction, usize)]) -> usize {
+	let mut head = Knot::new(None);
+	let mut current_knot = &mut head;
+
+	for _ in 1..10 {
+		let new_knot = Knot::new(None);
+
+		current_knot.follower = Some(Box::new(new_knot));
+		current_knot = current_knot.follower.as_mut().unwrap();
+	}
+
+	for step in steps(motions) {
+		head._move(step);
+	}
+
+	let mut last_knot = &head;
+	while let Some(ref follower) = last_knot.follower {
+		last_knot = follower;
+	}
+
+	last_knot.visited_positions.len()
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+	const DAY: u8 = 9;
+
+	type Model = Vec<(Direction, usize)>;
+	type PartOne = usize;
+	type PartTwo = usize;
+
+	fn parse(input: &str) -> auxiliary::Result<Self::Model> {
+		parse(input)
+	}
+
+	fn part_one(model: &Self::Model) -> Self::PartOne {
+		part_one(model)
+	}
+
+	fn part_two(model: &Self::Model) -> Self::PartTwo {
+		part_two(model)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	const EXAMPLE: &str = include_str!("../example.txt");
+	const LARGER_EXAMPLE: &str = include_str!("../example2.txt");
+
+	#[test]
+	fn test_part_one_example() {
+		let model = Day09::parse(EXAMPLE).unwrap();
+		assert_eq!(Day09::part_one(&model), 13);
+	}
+
+	#[test]
+	fn test_part_two_example() {
+		let model = Day09::parse(EXAMPLE).unwrap();
+		assert_eq!(Day09::part_two(&model), 1);
+	}
+
+	#[test]
+	fn test_part_two_larger_example() {
+		let model = Day09::parse(LARGER_EXAMPLE).unwrap();
+		assert_eq!(Day09::part_two(&model), 36);
+	}
+}
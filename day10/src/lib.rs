@@ -0,0 +1,123 @@
+use auxiliary::grid::Grid;
+use auxiliary::solution::Solution;
+use auxiliary::Error;
+
+pub enum CpuInstruction {
+	Noop,
+	AddX(i32),
+}
+
+impl CpuInstruction {
+	fn from_str(raw_instruction: &str) -> Option<CpuInstruction> {
+		match raw_instruction.split_once(' ') {
+			None if raw_instruction == "noop" => Some(CpuInstruction::Noop),
+			Some(("addx", raw_number)) => raw_number.parse::<i32>().ok().map(CpuInstruction::AddX),
+			_ => None,
+		}
+	}
+}
+
+fn register_value_at(instruction_operations: &[i32], target_cycle: usize) -> i32 {
+	instruction_operations
+		.iter()
+		.take(target_cycle - 1)
+		.cloned()
+		.fold(1, |number, acc| acc + number)
+}
+
+// todo: better naming
+fn calculate_register_states(cpu_instructions: &[CpuInstruction]) -> Vec<i32> {
+	cpu_instructions
+		.iter()
+		.flat_map(|instruction| match instruction {
+			CpuInstruction::Noop => vec![0],
+			CpuInstruction::AddX(x) => vec![0, *x],
+		})
+		.collect()
+}
+
+pub fn parse(input: &str) -> auxiliary::Result<Vec<CpuInstruction>> {
+	input
+		.lines()
+		.enumerate()
+		.map(|(idx, line)| {
+			CpuInstruction::from_str(line)
+				.ok_or_else(|| Error::parse(idx + 1, format!("invalid instruction {:?}", line)))
+		})
+		.collect()
+}
+
+pub fn part_one(instructions: &[CpuInstruction]) -> i32 {
+	let register_states: Vec<i32> = calculate_register_states(instructions);
+	let signal_strength_at = | target_cycle: usize | {
+		register_value_at(&register_states, target_cycle) * target_cycle as i32
+	};
+
+	[ 20, 60, 100, 140, 180, 220 ].map(signal_strength_at).into_iter().sum()
+}
+
+pub fn part_two(instructions: &[CpuInstruction]) -> Grid<bool> {
+	let register_states: Vec<i32> = calculate_register_states(instructions);
+
+	let pixels = (0..240)
+		.map(|cycle: i32| {
+			let drawn_pixel_pos = cycle % 40;
+			let sprite_pos = register_value_at(&register_states, (cycle + 1) as usize) - 1;
+			drawn_pixel_pos >= sprite_pos && drawn_pixel_pos <= sprite_pos + 2
+		})
+		.collect();
+	Grid::from_cells(40, pixels)
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+	const DAY: u8 = 10;
+
+	type Model = Vec<CpuInstruction>;
+	type PartOne = i32;
+	type PartTwo = Grid<bool>;
+
+	fn parse(input: &str) -> auxiliary::Result<Self::Model> {
+		parse(input)
+	}
+
+	fn part_one(model: &Self::Model) -> Self::PartOne {
+		part_one(model)
+	}
+
+	fn part_two(model: &Self::Model) -> Self::PartTwo {
+		part_two(model)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use auxiliary::Answer;
+
+	const EXAMPLE: &str = include_str!("../example.txt");
+
+	#[test]
+	fn test_part_one_example() {
+		let model = Day10::parse(EXAMPLE).unwrap();
+		assert_eq!(Day10::part_one(&model), 13140);
+	}
+
+	#[test]
+	fn test_part_two_example() {
+		let model = Day10::parse(EXAMPLE).unwrap();
+		assert_eq!(
+			Answer::from(Day10::part_two(&model)).to_string(),
+			[
+				"##..##..##..##..##..##..##..##..##..##..",
+				"###...###...###...###...###...###...###.",
+				"####....####....####....####....####....",
+				"#####.....#####.....#####.....#####.....",
+				"######......######......######......####",
+				"#######.......#######.......#######.....",
+			]
+			.join("\n")
+		);
+	}
+}
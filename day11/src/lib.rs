@@ -0,0 +1,287 @@
+use auxiliary::diagnostics::{Diagnostics, Fault};
+use auxiliary::io_;
+use auxiliary::parse::{
+	integer, literal, map, or, preceded, run, separated, trimmed, PResult, Parser,
+};
+use auxiliary::solution::Solution;
+use std::collections::VecDeque;
+use std::ops::{Add, Mul};
+
+#[derive(Clone, Copy)]
+enum Operation {
+	Add(Option<u64>),
+	Mul(Option<u64>),
+}
+
+impl Operation {
+	fn parse(raw_operation: &str) -> PResult<'_, Self> {
+		let operand = || or(map(literal("old"), |_| None), map(integer, Some));
+		or(
+			map(preceded("* ", operand()), Operation::Mul),
+			map(preceded("+ ", operand()), Operation::Add),
+		)(raw_operation)
+	}
+
+	fn apply(self, lhs: &u64) -> u64 {
+		match self {
+			Operation::Add(rhs) => lhs.add(rhs.unwrap_or(*lhs)),
+			Operation::Mul(rhs) => lhs.mul(rhs.unwrap_or(*lhs)),
+		}
+	}
+}
+
+#[derive(Clone)]
+pub struct Monkey {
+	items: VecDeque<u64>,
+	inspection_count: u64,
+	test_divider: u64,
+	test_dest_true: usize,
+	test_dest_false: usize,
+	mutation_op: Operation,
+}
+
+type DestinationMonkey = usize;
+
+struct MonkeyNotes<'a, 'd> {
+	lines: &'a [&'a str],
+	first_line: usize,
+	monkey_count: usize,
+	diagnostics: &'d mut Diagnostics,
+}
+
+impl<'a> MonkeyNotes<'a, '_> {
+	// Every detail is checked even after a failure so one run reports all of them.
+	fn detail<T>(
+		&mut self,
+		idx: usize,
+		prefix: &'static str,
+		parser: impl Parser<'a, T>,
+	) -> Option<T> {
+		let (idx, result) = match self.lines.get(idx) {
+			Some(line) => (
+				idx,
+				run(trimmed(preceded(prefix, parser)), line).map_err(|error| error.fault(line)),
+			),
+			None => {
+				let last = self.lines.len() - 1;
+				let missing = Fault::after(self.lines[last], format!("missing {:?}", prefix));
+				(last, Err(missing))
+			}
+		};
+		result
+			.map_err(|fault| {
+				self.diagnostics
+					.report(self.first_line + idx, self.lines[idx], fault)
+			})
+			.ok()
+	}
+
+	// For details that parse but make no sense; the value always ends the line.
+	fn reject(&mut self, idx: usize, message: String) -> bool {
+		let line = self.lines[idx];
+		let value = line.trim_end().rsplit(' ').next().unwrap_or(line);
+		self.diagnostics
+			.report(self.first_line + idx, line, Fault::new(value, message));
+		false
+	}
+
+	fn destination(&mut self, idx: usize, prefix: &'static str) -> Option<usize> {
+		self.detail(idx, prefix, integer).filter(|&destination| {
+			destination < self.monkey_count
+				|| self.reject(idx, format!("there is no monkey {}", destination))
+		})
+	}
+}
+
+impl Monkey {
+	fn from_raw(notes: &mut MonkeyNotes) -> Option<Self> {
+		let items = notes.detail(1, "Starting items: ", separated(integer, ", "));
+		let mutation_op = notes.detail(2, "Operation: new = old ", Operation::parse);
+		let test_divider = notes
+			.detail(3, "Test: divisible by ", integer)
+			.filter(|&divider| divider > 0 || notes.reject(3, "cannot divide by zero".to_string()));
+		let test_dest_true = notes.destination(4, "If true: throw to monkey ");
+		let test_dest_false = notes.destination(5, "If false: throw to monkey ");
+		Some(Monkey {
+			items: VecDeque::from(items?),
+			mutation_op: mutation_op?,
+			inspection_count: 0,
+			test_divider: test_divider?,
+			test_dest_true: test_dest_true?,
+			test_dest_false: test_dest_false?,
+		})
+	}
+
+	fn inspect_items<Modifier>(&mut self, worry_modifier: Modifier) -> Vec<(u64, DestinationMonkey)>
+	where
+		Modifier: Fn(&u64) -> u64,
+	{
+		let result = self
+			.items
+			.iter_mut()
+			.map(|item| {
+				self.inspection_count += 1;
+				*item = self.mutation_op.apply(item);
+				*item = worry_modifier(item);
+				match *item % self.test_divider == 0 {
+					true => (*item, self.test_dest_true),
+					false => (*item, self.test_dest_false),
+				}
+			})
+			.collect();
+
+		self.items.clear();
+
+		result
+	}
+}
+
+fn calculate_monkey_business(monkeys: &mut [Monkey]) -> u64 {
+	monkeys.sort_by(|lhs, rhs| {
+		rhs.inspection_count
+			.partial_cmp(&lhs.inspection_count)
+			.unwrap()
+	});
+	monkeys
+		.iter()
+		.take(2)
+		.fold(1, |a, b| a * b.inspection_count)
+}
+
+pub fn parse(input: &str) -> auxiliary::Result<Vec<Monkey>> {
+	let mut diagnostics = Diagnostics::new();
+	let mut monkeys = Vec::new();
+
+	let groups = io_::split_groups(input.lines()).collect::<Vec<_>>();
+	for (first_line, raw_monkey_details) in &groups {
+		let mut notes = MonkeyNotes {
+			lines: raw_monkey_details,
+			first_line: *first_line,
+			monkey_count: groups.len(),
+			diagnostics: &mut diagnostics,
+		};
+		monkeys.extend(Monkey::from_raw(&mut notes));
+	}
+	diagnostics.finish(monkeys)
+}
+
+pub fn part_one(monkeys: &[Monkey]) -> u64 {
+	let mut monkeys = monkeys.to_vec();
+
+	for _ in 0..20 {
+		for idx in 0..monkeys.len() {
+			monkeys
+				.get_mut(idx)
+				.unwrap()
+				.inspect_items(move |worry_level| worry_level.div_euclid(3))
+				.into_iter()
+				.for_each(|(item, destination_monkey)| {
+					monkeys[destination_monkey].items.push_back(item);
+				})
+		}
+	}
+	calculate_monkey_business(&mut monkeys)
+}
+
+pub fn part_two(monkeys: &[Monkey]) -> u64 {
+	let mut monkeys = monkeys.to_vec();
+	let least_common_multiple: u64 = monkeys
+		.iter()
+		.fold(1, |lcm, monkey| lcm * monkey.test_divider);
+
+	for _ in 0..10000 {
+		for idx in 0..monkeys.len() {
+			monkeys
+				.get_mut(idx)
+				.unwrap()
+				.inspect_items(move |worry_level| worry_level % least_common_multiple)
+				.into_iter()
+				.for_each(|(item, destination_monkey)| {
+					monkeys[destination_monkey].items.push_back(item);
+				})
+		}
+	}
+	calculate_monkey_business(&mut monkeys)
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+	const DAY: u8 = 11;
+
+	type Model = Vec<Monkey>;
+	type PartOne = u64;
+	type PartTwo = u64;
+
+	fn parse(input: &str) -> auxiliary::Result<Self::Model> {
+		parse(input)
+	}
+
+	fn part_one(model: &Self::Model) -> Self::PartOne {
+		part_one(model)
+	}
+
+	fn part_two(model: &Self::Model) -> Self::PartTwo {
+		part_two(model)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	const EXAMPLE: &str = include_str!("../example.txt");
+
+	#[test]
+	fn test_part_one_example() {
+		let model = Day11::parse(EXAMPLE).unwrap();
+		assert_eq!(Day11::part_one(&model), 10605);
+	}
+
+	#[test]
+	fn test_part_two_example() {
+		let model = Day11::parse(EXAMPLE).unwrap();
+		assert_eq!(Day11::part_two(&model), 2713310158);
+	}
+
+	#[test]
+	fn test_parse_reports_every_bad_detail() {
+		let input = EXAMPLE
+			.replacen("new = old * 19", "new = old ^ 19", 1)
+			.replacen("divisible by 13", "divisible by x", 1);
+		match parse(&input) {
+			Err(auxiliary::Error::Diagnostics(diagnostics)) => assert_eq!(
+				diagnostics
+					.iter()
+					.map(|diagnostic| (diagnostic.line, diagnostic.column))
+					.collect::<Vec<_>>(),
+				vec![(3, 24), (18, 22)]
+			),
+			_ => panic!("expected diagnostics"),
+		}
+	}
+
+	#[test]
+	fn test_parse_checks_destinations_and_dividers() {
+		let monkey = |divider: &str, destination: &str| {
+			format!(
+				"Monkey 0:\n  Starting items: 79\n  Operation: new = old * 19\n  \
+				Test: divisible by {}\n    If true: throw to monkey {}\n    \
+				If false: throw to monkey 0\n",
+				divider, destination
+			)
+		};
+		match parse(&monkey("0", "5")) {
+			Err(auxiliary::Error::Diagnostics(diagnostics)) => assert_eq!(
+				diagnostics
+					.iter()
+					.map(|diagnostic| (diagnostic.line, diagnostic.column, diagnostic.message.as_str()))
+					.collect::<Vec<_>>(),
+				vec![(4, 22, "cannot divide by zero"), (5, 30, "there is no monkey 5")]
+			),
+			_ => panic!("expected diagnostics"),
+		}
+		let monkeys = parse(&monkey("307", "0")).unwrap();
+		assert_eq!(monkeys[0].test_divider, 307);
+	}
+}
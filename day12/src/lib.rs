@@ -0,0 +1,186 @@
+use auxiliary::direction::CARDINAL;
+use auxiliary::grid::Grid;
+use auxiliary::point::Point2;
+use auxiliary::search::{bfs, multi_source_bfs};
+use auxiliary::solution::Solution;
+use auxiliary::Error;
+
+#[derive(Default, Clone)]
+struct GraphNode {
+	edges: Vec<Point2<usize>>,
+}
+
+impl GraphNode {
+	fn add_edge(&mut self, edge: Point2<usize>) {
+		self.edges.push(edge);
+	}
+}
+
+fn elevation(square: char) -> u32 {
+	match square {
+		'S' => 'a' as u32,
+		'E' => 'z' as u32,
+		_ => square as u32,
+	}
+}
+
+pub struct Graph {
+	nodes: Grid<GraphNode>,
+	starting_points: Vec<Point2<usize>>,
+	starting_point: Point2<usize>,
+	goal_point: Point2<usize>,
+}
+
+impl Graph {
+	fn from_raw(raw_graph: &str) -> auxiliary::Result<Self> {
+		let heightmap = Grid::parse(raw_graph.lines(), |square| {
+			Some(square).filter(|square| square.is_ascii_lowercase() || matches!(square, 'S' | 'E'))
+		})?;
+		let mut nodes: Grid<GraphNode> =
+			Grid::new(heightmap.width(), heightmap.height(), Default::default());
+		let mut starting_points: Vec<Point2<usize>> = Vec::new();
+		let mut original_starting_point = None;
+		let mut goal_point = None;
+
+		for (point, &square) in heightmap.iter() {
+			let marked = match square {
+				'a' => {
+					starting_points.push(point);
+					None
+				}
+				'S' => {
+					starting_points.push(point);
+					original_starting_point.replace(point)
+				}
+				'E' => goal_point.replace(point),
+				_ => None,
+			};
+			if marked.is_some() {
+				return Err(Error::parse(point.y + 1, format!("more than one {:?} square", square)));
+			}
+			let current_height = elevation(square);
+			let can_climb = |target_height| target_height <= (current_height + 1);
+			for direction in CARDINAL {
+				let neighbour = match heightmap.towards(point, direction) {
+					Some(neighbour) => neighbour,
+					None => continue,
+				};
+				if can_climb(elevation(heightmap[neighbour])) {
+					nodes[point].add_edge(neighbour);
+				}
+			}
+		}
+
+		let missing = |square: char| Error::Parse {
+			input: None,
+			line: None,
+			message: format!("no {:?} square", square),
+		};
+		Ok(Graph {
+			nodes,
+			starting_points,
+			starting_point: original_starting_point.ok_or_else(|| missing('S'))?,
+			goal_point: goal_point.ok_or_else(|| missing('E'))?,
+		})
+	}
+
+	fn get_original_starting_point(&self) -> Point2<usize> {
+		self.starting_point
+	}
+
+	fn get_goal_point(&self) -> Point2<usize> {
+		self.goal_point
+	}
+
+	fn get_successors_of(&self, point: Point2<usize>) -> Vec<Point2<usize>> {
+		self.nodes[point].edges.clone()
+	}
+}
+
+pub fn parse(input: &str) -> auxiliary::Result<Graph> {
+	Graph::from_raw(input)
+}
+
+pub fn part_one(graph: &Graph) -> auxiliary::Result<usize> {
+	let paths = bfs(graph.get_original_starting_point(), |point| graph.get_successors_of(point));
+
+	paths
+		.distance(graph.get_goal_point())
+		.ok_or_else(|| Error::NoAnswer("E cannot be reached from S".to_string()))
+}
+
+pub fn part_two(graph: &Graph) -> auxiliary::Result<usize> {
+	let paths = multi_source_bfs(graph.starting_points.iter().copied(), |point| {
+		graph.get_successors_of(point)
+	});
+
+	paths
+		.distance(graph.get_goal_point())
+		.ok_or_else(|| Error::NoAnswer("E cannot be reached from any a square".to_string()))
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+	const DAY: u8 = 12;
+
+	type Model = Graph;
+	type PartOne = auxiliary::Result<usize>;
+	type PartTwo = auxiliary::Result<usize>;
+
+	fn parse(input: &str) -> auxiliary::Result<Self::Model> {
+		parse(input)
+	}
+
+	fn part_one(model: &Self::Model) -> Self::PartOne {
+		part_one(model)
+	}
+
+	fn part_two(model: &Self::Model) -> Self::PartTwo {
+		part_two(model)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	const EXAMPLE: &str = include_str!("../example.txt");
+
+	#[test]
+	fn test_part_one_example() {
+		let model = Day12::parse(EXAMPLE).unwrap();
+		assert_eq!(Day12::part_one(&model).unwrap(), 31);
+	}
+
+	#[test]
+	fn test_part_two_example() {
+		let model = Day12::parse(EXAMPLE).unwrap();
+		assert_eq!(Day12::part_two(&model).unwrap(), 29);
+	}
+
+	#[test]
+	fn test_parse_checks_the_heightmap() {
+		let message = |input: &str| match Day12::parse(input) {
+			Err(Error::Parse { line, message, .. }) => (line, message),
+			_ => panic!("expected a parse error"),
+		};
+		assert_eq!(message("Sab
+ab1
+zzE"), (Some(2), "unexpected character '1'".to_string()));
+		assert_eq!(message("Sab
+SbE"), (Some(2), "more than one 'S' square".to_string()));
+		assert_eq!(message("Sab
+abc"), (None, "no 'E' square".to_string()));
+	}
+
+	#[test]
+	fn test_unreachable_goal() {
+		let model = Day12::parse("SbE").unwrap();
+		assert_eq!(
+			Day12::part_one(&model).unwrap_err().to_string(),
+			"No answer: E cannot be reached from S."
+		);
+		assert!(matches!(Day12::part_two(&model), Err(Error::NoAnswer(_))));
+	}
+}
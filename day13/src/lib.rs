@@ -0,0 +1,197 @@
+use auxiliary::diagnostics::{Diagnostics, Fault};
+use auxiliary::io_;
+use auxiliary::parse::{integer, literal, map, or, run, separated, PResult};
+use auxiliary::solution::Solution;
+use std::cmp::Ordering;
+
+#[derive(Clone, Debug)]
+pub enum Packet {
+	Integer(u32),
+	List(Vec<Packet>),
+}
+
+use Packet::{Integer, List};
+
+fn parse_packet(input: &str) -> PResult<'_, Packet> {
+	or(map(integer, Integer), parse_list)(input)
+}
+
+fn parse_list(input: &str) -> PResult<'_, Packet> {
+	let (_, input) = literal("[")(input)?;
+	if let Ok((_, rest)) = literal("]")(input) {
+		return Ok((List(Vec::new()), rest));
+	}
+	let (packets, input) = separated(parse_packet, ",")(input)?;
+	let (_, input) = literal("]")(input)?;
+	Ok((List(packets), input))
+}
+
+// An integer compared with a list is treated as a list holding just that
+// integer, so equality has to follow the ordering rather than the shape.
+impl Ord for Packet {
+	fn cmp(&self, other: &Self) -> Ordering {
+		match (self, other) {
+			(Integer(left), Integer(right)) => left.cmp(right),
+			(List(left), List(right)) => left.cmp(right),
+			(Integer(left), List(_)) => List(vec![Integer(*left)]).cmp(other),
+			(List(_), Integer(right)) => self.cmp(&List(vec![Integer(*right)])),
+		}
+	}
+}
+
+impl PartialOrd for Packet {
+	fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+		Some(self.cmp(other))
+	}
+}
+
+impl PartialEq for Packet {
+	fn eq(&self, other: &Self) -> bool {
+		self.cmp(other) == Ordering::Equal
+	}
+}
+
+impl Eq for Packet {}
+
+pub fn parse(input: &str) -> auxiliary::Result<Vec<(Packet, Packet)>> {
+	let mut diagnostics = Diagnostics::new();
+	let mut packet_pairs = Vec::new();
+	for (first_line, raw_pair) in io_::split_groups(input.lines()) {
+		let mut packets = Vec::new();
+		for (line, raw_packet) in (first_line..).zip(&raw_pair) {
+			match run(parse_list, raw_packet) {
+				Ok(packet) => packets.push(packet),
+				Err(error) => diagnostics.report(line, raw_packet, error.fault(raw_packet)),
+			}
+		}
+		match raw_pair[..] {
+			[_, _] => {}
+			[single] => {
+				let fault = Fault::after(single, "expected a second packet on the next line");
+				diagnostics.report(first_line, single, fault);
+			}
+			[_, _, extra, ..] => {
+				let fault = Fault::new(extra, "expected a blank line after each pair");
+				diagnostics.report(first_line + 2, extra, fault);
+			}
+			[] => {}
+		}
+		if let [left, right] = &packets[..] {
+			packet_pairs.push((left.clone(), right.clone()));
+		}
+	}
+	diagnostics.finish(packet_pairs)
+}
+
+pub fn part_one(packet_pairs: &[(Packet, Packet)]) -> usize {
+	packet_pairs
+		.iter()
+		.zip(1..)
+		.filter(|((left, right), _)| left < right)
+		.map(|(_, index)| index)
+		.sum()
+}
+
+// Sorting is not needed: a divider's position is one more than the number of
+// packets that sort before it.
+pub fn part_two(packet_pairs: &[(Packet, Packet)]) -> usize {
+	let divider = |value| List(vec![List(vec![Integer(value)])]);
+	let (first, second) = (divider(2), divider(6));
+	let packets = packet_pairs.iter().flat_map(|(left, right)| [left, right]);
+	let before_first = packets.clone().filter(|&packet| *packet < first).count();
+	let before_second = packets.filter(|&packet| *packet < second).count();
+	(before_first + 1) * (before_second + 2)
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+	const DAY: u8 = 13;
+
+	type Model = Vec<(Packet, Packet)>;
+	type PartOne = usize;
+	type PartTwo = usize;
+
+	fn parse(input: &str) -> auxiliary::Result<Self::Model> {
+		parse(input)
+	}
+
+	fn part_one(model: &Self::Model) -> Self::PartOne {
+		part_one(model)
+	}
+
+	fn part_two(model: &Self::Model) -> Self::PartTwo {
+		part_two(model)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	const EXAMPLE: &str = include_str!("../example.txt");
+
+	fn compare(left: &str, right: &str) -> Ordering {
+		run(parse_list, left).unwrap().cmp(&run(parse_list, right).unwrap())
+	}
+
+	#[test]
+	fn test_compare() {
+		assert_eq!(
+			compare("[1,[2,[3,[4,[5,6,7]]]],8,9]", "[1,[2,[3,[4,[5,6,7]]]],8,9]"),
+			Ordering::Equal
+		);
+		assert_eq!(
+			compare("[1,[2,[3,[4,[5,6,7]]]],8,9]", "[1,[2,[3,[4,[5,6,0]]]],8,9]"),
+			Ordering::Greater
+		);
+		assert_eq!(compare("[1,1,3,1,1]", "[1,1,5,1,1]"), Ordering::Less);
+		assert_eq!(compare("[[1],[2,3,4]]", "[[1],4]"), Ordering::Less);
+		assert_eq!(compare("[9]", "[[8,7,6]]"), Ordering::Greater);
+		assert_eq!(compare("[[4,4],4,4]", "[[4,4],4,4,4]"), Ordering::Less);
+		assert_eq!(compare("[7,7,7,7]", "[7,7,7]"), Ordering::Greater);
+		assert_eq!(compare("[]", "[3]"), Ordering::Less);
+		assert_eq!(compare("[[[]]]", "[[]]"), Ordering::Greater);
+		assert_eq!(compare("[[2]]", "[2]"), Ordering::Equal);
+		assert_eq!(
+			compare(
+				"[[[[10,6],3,[9,6,7,9,7]],[[2,4,10,7,1],[7,9],[8,2,9,9,2],5,[1]],5,[]],[[[8,6,6,9,1],1],[7,[8,3],9,4,[0,3,10,9,7]]],[[[10,1]],0,[],[[4,1],[3],[10,6,4],10]],[8,[7],2,9],[[],2,[[3,6,3,6],4,[8,7,4,7,2],3]]]",
+				"[[8,9,[[]]],[],[]]"
+			),
+			Ordering::Greater
+		);
+		assert_eq!(
+			compare(
+				"[[2,3,[]],[[6,4,[],[10,6,8,5,8],6],[1,5,[9,0,1,8,10],[5,8,8],5],[[],[4,6,0],[3,4,5,4,4],8],[[7,6]]],[]]",
+				"[[[2],[[9,5,3,6]],[[],[5,9],[3],[10,9,4,1,7]],[4]],[[],[[],8,[2,8],3,[2,7,5,2,6]]],[],[2,[[1],[],4,[3,2,2,1,1],2],[[3,7,6,10,1],10],[[],[0],4,[4,0]]]]"
+			),
+			Ordering::Less
+		)
+	}
+
+	#[test]
+	fn test_part_one_example() {
+		let model = Day13::parse(EXAMPLE).unwrap();
+		assert_eq!(Day13::part_one(&model), 13);
+	}
+
+	#[test]
+	fn test_part_two_example() {
+		let model = Day13::parse(EXAMPLE).unwrap();
+		assert_eq!(Day13::part_two(&model), 140);
+	}
+
+	#[test]
+	fn test_parse_reports_malformed_packets() {
+		match parse("[1,2\n[3]\n\n[x]\n[]\n\n[1]\n") {
+			Err(auxiliary::Error::Diagnostics(diagnostics)) => assert_eq!(
+				diagnostics
+					.iter()
+					.map(|diagnostic| (diagnostic.line, diagnostic.column))
+					.collect::<Vec<_>>(),
+				vec![(1, 5), (4, 2), (7, 4)]
+			),
+			_ => panic!("expected diagnostics"),
+		}
+	}
+}
@@ -0,0 +1,218 @@
+use auxiliary::diagnostics::Diagnostics;
+use auxiliary::direction::Direction;
+use auxiliary::grid::Grid;
+use auxiliary::parse::{integer, preceded, run, separated, PResult};
+use auxiliary::point::Point2;
+use auxiliary::solution::Solution;
+
+type Point = Point2<usize>;
+pub type RockPath = Vec<Point>;
+
+fn parse_point(raw_point: &str) -> PResult<'_, Point> {
+	let (x, input) = integer(raw_point)?;
+	let (y, input) = preceded(",", integer)(input)?;
+	Ok((Point2::new(x, y), input))
+}
+
+fn parse_rock_path(raw_path: &str) -> PResult<'_, RockPath> {
+	separated(parse_point, " -> ")(raw_path)
+}
+
+#[derive(Clone, PartialEq)]
+enum CaveLocation {
+	Air,
+	Rock,
+	Sand,
+}
+
+const FALLS: [Direction; 3] = [Direction::South, Direction::SouthWest, Direction::SouthEast];
+
+type CaveGrid = Grid<CaveLocation>;
+
+// Bresenham's algorithm
+fn draw_line(cave_grid: &mut CaveGrid, from: Point, to: Point) {
+	let (x1, y1, x2, y2) = (from.x, from.y, to.x, to.y);
+	let mut x = x1 as isize;
+	let mut y = y1 as isize;
+
+	let dx = (x2 as isize - x1 as isize).abs();
+	let dy = (y2 as isize - y1 as isize).abs();
+
+	let sx: isize = if x1 < x2 { 1 } else { -1 };
+	let sy: isize = if y1 < y2 { 1 } else { -1 };
+
+	let mut error = if dx > dy { dx } else { -dy } / 2;
+	let mut err2;
+
+	loop {
+		cave_grid[Point2::new(x as usize, y as usize)] = CaveLocation::Rock;
+
+		if x == (x2 as isize) && y == (y2 as isize) {
+			break;
+		}
+
+		err2 = 2 * error;
+
+		if err2 > -dx {
+			error -= dy;
+			x += sx;
+		}
+
+		if err2 < dy {
+			error += dx;
+			y += sy;
+		}
+	}
+}
+
+fn apply_rock_path(cave_grid: &mut CaveGrid, rock_path: &RockPath) {
+	rock_path
+		.iter()
+		.as_slice()
+		.windows(2)
+		.for_each(|rock_wall_points| {
+			draw_line(cave_grid, rock_wall_points[0], rock_wall_points[1])
+		});
+}
+
+enum DripResult {
+	LandingPoint(Point),
+	Abyss,
+}
+
+// Sand falling past any edge of the cave is lost to the abyss.
+fn drip_sand(cave_grid: &CaveGrid, drip_point: Point) -> DripResult {
+	for fall in FALLS {
+		let next = match fall.apply(drip_point) {
+			Some(next) => next,
+			None => return DripResult::Abyss,
+		};
+		match cave_grid.get(next) {
+			None => return DripResult::Abyss,
+			Some(CaveLocation::Air) => return drip_sand(cave_grid, next),
+			Some(_) => {}
+		}
+	}
+	DripResult::LandingPoint(drip_point)
+}
+
+const SAND_SOURCE: Point = Point2::new(500, 0);
+
+fn max_boundaries(rock_paths: &[RockPath]) -> Point {
+	let points = rock_paths.iter().flatten().copied().chain([SAND_SOURCE]);
+	Point2::bounding_box(points).map_or(SAND_SOURCE, |(_, max)| max)
+}
+
+// The cave covers every rock and the source. With a floor, sand can spread as
+// far sideways as the floor is deep, so everything is shifted right by that
+// depth to keep the pile inside the grid.
+fn build_cave(rock_paths: &[RockPath], floor: Option<usize>) -> (CaveGrid, Point) {
+	let path_boundaries = max_boundaries(rock_paths);
+	let shift = floor.unwrap_or(0);
+	let width = path_boundaries.x.max(SAND_SOURCE.x + shift) + shift + 1;
+	let height = floor.unwrap_or(path_boundaries.y) + 1;
+	let mut cave = Grid::new(width, height, CaveLocation::Air);
+
+	let shifted = |point: &Point| Point2::new(point.x + shift, point.y);
+	for rock_path in rock_paths {
+		apply_rock_path(&mut cave, &rock_path.iter().map(shifted).collect());
+	}
+	if let Some(floor) = floor {
+		apply_rock_path(&mut cave, &vec![Point2::new(0, floor), Point2::new(width - 1, floor)]);
+	}
+	(cave, shifted(&SAND_SOURCE))
+}
+
+// Sand keeps coming until it falls into the abyss or piles up to the source.
+fn pour_sand(cave: &mut CaveGrid, source: Point) -> i32 {
+	let mut counter = 0;
+	while cave[source] == CaveLocation::Air {
+		match drip_sand(cave, source) {
+			DripResult::LandingPoint(point) => {
+				cave[point] = CaveLocation::Sand;
+				counter += 1;
+			}
+			DripResult::Abyss => break,
+		}
+	}
+	counter
+}
+
+pub fn parse(input: &str) -> auxiliary::Result<Vec<RockPath>> {
+	let mut diagnostics = Diagnostics::new();
+	let mut rock_paths = Vec::new();
+	for (idx, line) in input.lines().enumerate() {
+		match run(parse_rock_path, line) {
+			Ok(rock_path) => rock_paths.push(rock_path),
+			Err(error) => diagnostics.report(idx + 1, line, error.fault(line)),
+		}
+	}
+	diagnostics.finish(rock_paths)
+}
+
+pub fn part_one(rock_paths: &[RockPath]) -> i32 {
+	let (mut cave, source) = build_cave(rock_paths, None);
+	pour_sand(&mut cave, source)
+}
+
+pub fn part_two(rock_paths: &[RockPath]) -> i32 {
+	let (mut cave, source) = build_cave(rock_paths, Some(max_boundaries(rock_paths).y + 2));
+	pour_sand(&mut cave, source)
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+	const DAY: u8 = 14;
+
+	type Model = Vec<RockPath>;
+	type PartOne = i32;
+	type PartTwo = i32;
+
+	fn parse(input: &str) -> auxiliary::Result<Self::Model> {
+		parse(input)
+	}
+
+	fn part_one(model: &Self::Model) -> Self::PartOne {
+		part_one(model)
+	}
+
+	fn part_two(model: &Self::Model) -> Self::PartTwo {
+		part_two(model)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	const EXAMPLE: &str = include_str!("../example.txt");
+
+	#[test]
+	fn test_part_one_example() {
+		let model = Day14::parse(EXAMPLE).unwrap();
+		assert_eq!(Day14::part_one(&model), 24);
+	}
+
+	#[test]
+	fn test_part_two_example() {
+		let model = Day14::parse(EXAMPLE).unwrap();
+		assert_eq!(Day14::part_two(&model), 93);
+	}
+
+	#[test]
+	fn test_rocks_on_the_top_row() {
+		let model = Day14::parse("0,0 -> 3,0\n").unwrap();
+		assert_eq!(Day14::part_one(&model), 0);
+		assert_eq!(Day14::part_two(&model), 4);
+	}
+
+	#[test]
+	fn test_parse_rock_path() {
+		assert_eq!(
+			run(parse_rock_path, "498,4 -> 498,6 -> 496,6"),
+			Ok(vec![Point2::new(498, 4), Point2::new(498, 6), Point2::new(496, 6)])
+		);
+		assert_eq!(run(parse_rock_path, "498,4 -> 498").unwrap_err().column, 13);
+	}
+}
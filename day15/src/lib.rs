@@ -0,0 +1,154 @@
+use auxiliary::diagnostics::Diagnostics;
+use auxiliary::interval::{Interval, IntervalSet};
+use auxiliary::parse::{integer, key_value, preceded, run, PResult};
+use auxiliary::point::Point2;
+use auxiliary::solution::Solution;
+use std::collections::HashSet;
+
+const INSPECTED_LINE: i64 = 2000000;
+const MAX_RANGE: i64 = 4000000;
+
+type Point = Point2<i64>;
+type Distance = i64;
+pub type Sensor = Point;
+pub type Beacon = Point;
+
+fn coordinates(input: &str) -> PResult<'_, Point> {
+	let (x, input) = key_value("x", integer)(input)?;
+	let (y, input) = preceded(", ", key_value("y", integer))(input)?;
+	Ok((Point2::new(x, y), input))
+}
+
+fn parse_raw_sensor(sensor_string: &str) -> PResult<'_, Sensor> {
+	preceded("Sensor at ", coordinates)(sensor_string)
+}
+
+fn parse_raw_beacon(raw_beacon: &str) -> PResult<'_, Beacon> {
+	preceded("closest beacon is at ", coordinates)(raw_beacon)
+}
+
+fn parse_sensor_beacon(raw_input_row: &str) -> PResult<'_, (Sensor, Beacon)> {
+	let (sensor, input) = parse_raw_sensor(raw_input_row)?;
+	let (beacon, input) = preceded(": ", parse_raw_beacon)(input)?;
+	Ok(((sensor, beacon), input))
+}
+
+// The columns of `row` that are at least as close to some sensor as its beacon.
+fn coverage(sensor_distances: &[(Sensor, Distance)], row: i64) -> IntervalSet<i64> {
+	sensor_distances
+		.iter()
+		.filter_map(|&(sensor, distance)| {
+			let reach = distance - (sensor.y - row).abs();
+			(reach >= 0).then(|| Interval::inclusive(sensor.x - reach, sensor.x + reach))
+		})
+		.collect()
+}
+
+fn to_point_distances(sensor_beacons: &[(Sensor, Beacon)]) -> Vec<(Sensor, Distance)> {
+	sensor_beacons
+		.iter()
+		.map(|(sensor, beacon)| (*sensor, sensor.manhattan(*beacon)))
+		.collect::<Vec<(Sensor, Distance)>>()
+}
+
+pub fn parse(input: &str) -> auxiliary::Result<Vec<(Sensor, Beacon)>> {
+	let mut diagnostics = Diagnostics::new();
+	let mut sensor_and_beacons = Vec::new();
+	for (idx, line) in input.lines().enumerate() {
+		match run(parse_sensor_beacon, line) {
+			Ok(sensor_and_beacon) => sensor_and_beacons.push(sensor_and_beacon),
+			Err(error) => diagnostics.report(idx + 1, line, error.fault(line)),
+		}
+	}
+	diagnostics.finish(sensor_and_beacons)
+}
+
+pub fn part_one(sensor_and_beacons: &[(Sensor, Beacon)], inspected_line: i64) -> i64 {
+	let mut no_beacon = coverage(&to_point_distances(sensor_and_beacons), inspected_line);
+	let beacons_on_line = sensor_and_beacons
+		.iter()
+		.map(|&(_, beacon)| beacon)
+		.filter(|beacon| beacon.y == inspected_line)
+		.collect::<HashSet<Beacon>>();
+
+	for beacon in beacons_on_line {
+		no_beacon.subtract(Interval::inclusive(beacon.x, beacon.x));
+	}
+	no_beacon.total_len()
+}
+
+pub fn part_two(sensor_and_beacons: &[(Sensor, Beacon)], max_range: i64) -> i64 {
+	let sensor_distances = to_point_distances(sensor_and_beacons);
+	let (x, y) = (0..=max_range)
+		.find_map(|y| {
+			let mut uncovered = IntervalSet::from_iter([Interval::inclusive(0, max_range)]);
+			for covered in coverage(&sensor_distances, y).iter() {
+				uncovered.subtract(covered);
+			}
+			let gap = uncovered.iter().next();
+			gap.map(|gap| (gap.start, y))
+		})
+		.unwrap();
+
+	(x * 4000000) + y
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+	const DAY: u8 = 15;
+
+	type Model = Vec<(Sensor, Beacon)>;
+	type PartOne = i64;
+	type PartTwo = i64;
+
+	fn parse(input: &str) -> auxiliary::Result<Self::Model> {
+		parse(input)
+	}
+
+	fn part_one(model: &Self::Model) -> Self::PartOne {
+		part_one(model, INSPECTED_LINE)
+	}
+
+	fn part_two(model: &Self::Model) -> Self::PartTwo {
+		part_two(model, MAX_RANGE)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use auxiliary::parse::Expected;
+
+	const EXAMPLE: &str = include_str!("../example.txt");
+
+	#[test]
+	fn test_parse_sensor_string() {
+		assert_eq!(
+			run(parse_raw_sensor, "Sensor at x=3797530, y=3451192"),
+			Ok(Point2::new(3797530, 3451192))
+		);
+		let error = run(parse_raw_sensor, "Sensor at x=3797530, y=north").unwrap_err();
+		assert_eq!((error.column, error.expected), (24, Expected::Integer));
+	}
+
+	#[test]
+	fn test_parse_raw_beacon() {
+		assert_eq!(
+			run(parse_raw_beacon, "closest beacon is at x=3316341, y=3328308"),
+			Ok(Point2::new(3316341, 3328308))
+		)
+	}
+
+	#[test]
+	fn test_part_one_example() {
+		let model = Day15::parse(EXAMPLE).unwrap();
+		assert_eq!(part_one(&model, 10), 26);
+	}
+
+	#[test]
+	fn test_part_two_example() {
+		let model = Day15::parse(EXAMPLE).unwrap();
+		assert_eq!(part_two(&model, 20), 56000011);
+	}
+}
@@ -0,0 +1,114 @@
+use auxiliary::point::Point3;
+use auxiliary::search::flood_fill;
+use auxiliary::solution::Solution;
+use auxiliary::Error;
+use std::collections::HashSet;
+
+pub type Cube = Point3<i32>;
+
+fn parse_raw_coordinates(raw_coordinates: &str) -> Option<Cube> {
+	let parsed_coordinates = raw_coordinates
+		.split(',')
+		.map(|raw_coordinate| raw_coordinate.parse::<i32>().ok())
+		.collect::<Option<Vec<i32>>>()?;
+
+	match parsed_coordinates[..] {
+		[x, y, z] => Some(Point3::new(x, y, z)),
+		_ => None,
+	}
+}
+
+fn calculate_surfaces(points: &HashSet<Cube>) -> u16 {
+	points
+		.iter()
+		.map(|point| {
+			point
+				.neighbours6()
+				.filter(|neighbour| !points.contains(neighbour))
+				.count() as u16
+		})
+		.sum()
+}
+
+pub fn parse(input: &str) -> auxiliary::Result<HashSet<Cube>> {
+	input
+		.lines()
+		.enumerate()
+		.map(|(idx, line)| {
+			parse_raw_coordinates(line)
+				.ok_or_else(|| Error::parse(idx + 1, format!("invalid cube {:?}", line)))
+		})
+		.collect()
+}
+
+pub fn part_one(coordinates: &HashSet<Cube>) -> u16 {
+	calculate_surfaces(coordinates)
+}
+
+pub fn part_two(coordinates: &HashSet<Cube>) -> u16 {
+	let (min, max) = Point3::bounding_box(coordinates.iter().copied()).unwrap();
+	let min_coords = min - Point3::new(1, 1, 1);
+	let max_coords = max + Point3::new(1, 1, 1);
+	let water_path = flood_fill([min_coords], |current_point: Cube| {
+		current_point.neighbours6().filter(move |&coordinate| {
+			coordinate.within(min_coords, max_coords) && !coordinates.contains(&coordinate)
+		})
+	});
+
+	let lava_path: HashSet<Cube> = (min_coords.x..=max_coords.x)
+		.flat_map(|x| {
+			(min_coords.y..=max_coords.y)
+				.flat_map(move |y| (min_coords.z..=max_coords.z).map(move |z| Point3::new(x, y, z)))
+		})
+		.filter(|&coord| !water_path.contains(coord))
+		.collect();
+
+	calculate_surfaces(&lava_path)
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+	const DAY: u8 = 18;
+
+	type Model = HashSet<Cube>;
+	type PartOne = u16;
+	type PartTwo = u16;
+
+	fn parse(input: &str) -> auxiliary::Result<Self::Model> {
+		parse(input)
+	}
+
+	fn part_one(model: &Self::Model) -> Self::PartOne {
+		part_one(model)
+	}
+
+	fn part_two(model: &Self::Model) -> Self::PartTwo {
+		part_two(model)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	const EXAMPLE: &str = include_str!("../example.txt");
+
+	#[test]
+	fn test_parse_raw_coordinates() {
+		assert_eq!(parse_raw_coordinates("1,2,3"), Some(Point3::new(1, 2, 3)));
+		assert_eq!(parse_raw_coordinates("1,2"), None);
+	}
+
+	#[test]
+	fn test_part_one_example() {
+		let model = Day18::parse(EXAMPLE).unwrap();
+		assert_eq!(Day18::part_one(&model), 64);
+	}
+
+	#[test]
+	fn test_part_two_example() {
+		let model = Day18::parse(EXAMPLE).unwrap();
+		assert_eq!(Day18::part_two(&model), 58);
+	}
+}